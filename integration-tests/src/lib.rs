@@ -0,0 +1 @@
+//! Tests of the framework integrations of `paginator`. They are kept in this crate so the dependencies and the minimum supported Rust version of the frameworks do not apply to `paginator` itself.
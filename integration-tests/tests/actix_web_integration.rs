@@ -0,0 +1,94 @@
+use actix_web::{
+    body::to_bytes,
+    http::StatusCode,
+    test::{call_service, init_service, TestRequest},
+    web, App, Responder,
+};
+use paginator::{
+    actix_web::{Paginated, Pagination, PaginationRejection},
+    PageQuery, PaginatorBuilder,
+};
+
+async fn list(pagination: Pagination) -> Result<impl Responder, PaginationRejection> {
+    let paginator = pagination.builder(95)?.build_paginator()?;
+
+    Ok(Paginated::new(
+        format!("{}/{}", paginator.current_page(), paginator.total_pages()),
+        paginator,
+    ))
+}
+
+async fn unknown_total() -> impl Responder {
+    Paginated::new("more", PaginatorBuilder::unknown_total(2, true).build_paginator().unwrap())
+}
+
+#[actix_web::test]
+async fn extractor() {
+    let app = init_service(App::new().route("/items", web::get().to(list))).await;
+
+    let response = call_service(&app, TestRequest::get().uri("/items?page=3").to_request()).await;
+
+    assert_eq!(StatusCode::OK, response.status());
+    assert_eq!("3/5", to_bytes(response.into_body()).await.unwrap());
+
+    let response = call_service(&app, TestRequest::get().uri("/items?page=0").to_request()).await;
+
+    assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    assert_eq!("application/json", response.headers().get("content-type").unwrap());
+    assert_eq!(
+        r#"{"error":"the page number should be a positive integer"}"#,
+        to_bytes(response.into_body()).await.unwrap()
+    );
+
+    let response = call_service(&app, TestRequest::get().uri("/items?page=6").to_request()).await;
+
+    assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    assert_eq!(
+        r#"{"error":"6 > 5 (page > total_pages)"}"#,
+        to_bytes(response.into_body()).await.unwrap()
+    );
+
+    let app = init_service(
+        App::new()
+            .app_data(PageQuery::new().page_param("p").lenient(true))
+            .route("/items", web::get().to(list)),
+    )
+    .await;
+
+    let response =
+        call_service(&app, TestRequest::get().uri("/items?p=9&per_page=50").to_request()).await;
+
+    assert_eq!(StatusCode::OK, response.status());
+    assert_eq!("2/2", to_bytes(response.into_body()).await.unwrap());
+}
+
+#[actix_web::test]
+async fn headers() {
+    let app = init_service(
+        App::new()
+            .route("/items", web::get().to(list))
+            .route("/feed", web::get().to(unknown_total)),
+    )
+    .await;
+
+    let response = call_service(
+        &app,
+        TestRequest::get().uri("/items?sort=name&page=2&per_page=40").to_request(),
+    )
+    .await;
+
+    assert_eq!(StatusCode::OK, response.status());
+    assert_eq!(
+        r#"</items?sort=name&per_page=40&page=1>; rel="first", </items?sort=name&per_page=40&page=1>; rel="prev", </items?sort=name&per_page=40&page=3>; rel="next", </items?sort=name&per_page=40&page=3>; rel="last""#,
+        response.headers().get("link").unwrap()
+    );
+    assert_eq!("95", response.headers().get("x-total-count").unwrap());
+
+    let response = call_service(&app, TestRequest::get().uri("/feed").to_request()).await;
+
+    assert_eq!(
+        r#"</feed?page=1>; rel="first", </feed?page=1>; rel="prev", </feed?page=3>; rel="next""#,
+        response.headers().get("link").unwrap()
+    );
+    assert!(response.headers().get("x-total-count").is_none());
+}
@@ -0,0 +1,34 @@
+use askama::Template;
+use paginator::{PageItem, PageUrl, Paginator};
+
+#[derive(Template)]
+#[template(
+    source = r#"<ul>{% for item in items %}<li class="{{ item.kind() }}">{% if item.is_current() || item.is_disabled() || item.is_ellipsis() %}<span>{{ item.label() }}</span>{% else if let Some(href) = item.href(page_url) %}<a href="{{ href }}">{{ item.label() }}</a>{% endif %}</li>{% endfor %}</ul>"#,
+    ext = "html"
+)]
+struct Pagination<'a> {
+    items:    Vec<PageItem>,
+    page_url: &'a PageUrl<'a>,
+}
+
+#[test]
+fn methods() {
+    let page_url = PageUrl::new("/items?sort=name");
+
+    let paginator = Paginator::builder(3)
+        .current_page(3)
+        .has_next(paginator::YesNoDepends::Yes)
+        .build_paginator()
+        .unwrap();
+
+    let html = Pagination {
+        items: paginator.paginate(), page_url: &page_url
+    }
+    .render()
+    .unwrap();
+
+    assert_eq!(
+        r#"<ul><li class="prev"><a href="/items?sort=name&#38;page=2">«</a></li><li class="page"><a href="/items?sort=name&#38;page=1">1</a></li><li class="page"><a href="/items?sort=name&#38;page=2">2</a></li><li class="current_page"><span>3</span></li><li class="reserved_next"><span>»</span></li></ul>"#,
+        html
+    );
+}
@@ -0,0 +1,81 @@
+use axum::{body::Body, http::Request, response::Response, routing::get, Extension, Router};
+use paginator::{
+    axum::{Pagination, PaginationRejection},
+    PageQuery,
+};
+use tower::ServiceExt;
+
+async fn list(pagination: Pagination) -> Result<String, PaginationRejection> {
+    let paginator = pagination.paginator(95)?;
+
+    Ok(format!(
+        "{}/{} offset={}",
+        paginator.current_page(),
+        paginator.total_pages(),
+        paginator.offset().unwrap()
+    ))
+}
+
+async fn request(app: &Router, uri: &str) -> (u16, Option<String>, String) {
+    let response: Response = app
+        .clone()
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    let status = response.status().as_u16();
+    let content_type =
+        response.headers().get("content-type").map(|value| value.to_str().unwrap().to_string());
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+    (status, content_type, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn default_query() {
+    let app = Router::new().route("/items", get(list));
+
+    let (status, _, body) = request(&app, "/items").await;
+
+    assert_eq!(200, status);
+    assert_eq!("1/5 offset=0", body);
+
+    let (status, _, body) = request(&app, "/items?sort=name&page=3&per_page=10").await;
+
+    assert_eq!(200, status);
+    assert_eq!("3/10 offset=20", body);
+
+    let (status, content_type, body) = request(&app, "/items?page=abc").await;
+
+    assert_eq!(400, status);
+    assert_eq!(Some("application/json"), content_type.as_deref());
+    assert_eq!(r#"{"error":"the page number should be a positive integer"}"#, body);
+
+    let (status, _, body) = request(&app, "/items?page=6").await;
+
+    assert_eq!(400, status);
+    assert_eq!(r#"{"error":"6 > 5 (page > total_pages)"}"#, body);
+
+    let (status, _, body) = request(&app, "/items?per_page=101").await;
+
+    assert_eq!(400, status);
+    assert_eq!(r#"{"error":"101 > 100 (per_page > max_per_page)"}"#, body);
+}
+
+#[tokio::test]
+async fn configured_query() {
+    let app = Router::new()
+        .route("/items", get(list))
+        .layer(Extension(PageQuery::new().page_param("p").max_per_page(30).lenient(true)));
+
+    let (status, _, body) = request(&app, "/items?p=0").await;
+
+    assert_eq!(200, status);
+    assert_eq!("1/5 offset=0", body);
+
+    let (status, _, body) = request(&app, "/items?p=99&per_page=500").await;
+
+    assert_eq!(200, status);
+    assert_eq!("4/4 offset=90", body);
+}
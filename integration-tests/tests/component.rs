@@ -0,0 +1,29 @@
+use paginator::{component::page_for_key, Paginator, PaginatorBuilder};
+
+#[test]
+fn keyboard() {
+    let paginator = Paginator::builder(5).current_page(3).build_paginator().unwrap();
+
+    assert_eq!(Some(2), page_for_key(&paginator, "ArrowLeft"));
+    assert_eq!(Some(2), page_for_key(&paginator, "PageUp"));
+    assert_eq!(Some(4), page_for_key(&paginator, "ArrowRight"));
+    assert_eq!(Some(4), page_for_key(&paginator, "PageDown"));
+    assert_eq!(Some(1), page_for_key(&paginator, "Home"));
+    assert_eq!(Some(5), page_for_key(&paginator, "End"));
+    assert_eq!(None, page_for_key(&paginator, "Enter"));
+
+    let paginator = Paginator::builder(5).current_page(1).build_paginator().unwrap();
+
+    assert_eq!(None, page_for_key(&paginator, "ArrowUp"));
+    assert_eq!(None, page_for_key(&paginator, "Home"));
+
+    let paginator = PaginatorBuilder::unknown_total(5, true).build_paginator().unwrap();
+
+    assert_eq!(Some(6), page_for_key(&paginator, "ArrowDown"));
+    assert_eq!(None, page_for_key(&paginator, "End"));
+
+    let paginator = PaginatorBuilder::unknown_total(5, false).build_paginator().unwrap();
+
+    assert_eq!(None, page_for_key(&paginator, "ArrowRight"));
+    assert_eq!(None, page_for_key(&paginator, "End"));
+}
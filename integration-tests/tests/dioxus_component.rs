@@ -0,0 +1,27 @@
+use dioxus::prelude::*;
+use paginator::{dioxus::PaginationBar, Paginator, PaginatorBuilder, YesNoDepends};
+
+fn app(paginator: Paginator) -> Element {
+    rsx! {
+        PaginationBar { paginator, on_change: |_: usize| {} }
+    }
+}
+
+#[test]
+fn ssr() {
+    let mut dom = VirtualDom::new_with_props(
+        app,
+        PaginatorBuilder::new(20)
+            .current_page(20)
+            .has_next(YesNoDepends::Yes)
+            .build_paginator()
+            .unwrap(),
+    );
+
+    dom.rebuild_in_place();
+
+    assert_eq!(
+        r#"<nav aria-label="Pagination"><ul class="pagination"><li class="prev"><button type="button" aria-label="Previous page">«</button></li><li class="page"><button type="button" aria-label="Page 1">1</button></li><li class="ignore"><span aria-hidden="true">…</span></li><li class="page"><button type="button" aria-label="Page 16">16</button></li><li class="page"><button type="button" aria-label="Page 17">17</button></li><li class="page"><button type="button" aria-label="Page 18">18</button></li><li class="page"><button type="button" aria-label="Page 19">19</button></li><li class="current_page"><button type="button" aria-label="Page 20" aria-current="page">20</button></li><li class="reserved_next"><button type="button" aria-label="Next page" disabled=true>»</button></li></ul></nav>"#,
+        dioxus_ssr::render(&dom)
+    );
+}
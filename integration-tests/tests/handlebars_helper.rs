@@ -0,0 +1,53 @@
+use handlebars::Handlebars;
+use paginator::handlebars::PageItemsHelper;
+use serde_json::json;
+
+fn handlebars() -> Handlebars<'static> {
+    let mut handlebars = Handlebars::new();
+
+    handlebars.register_helper("page_items", Box::new(PageItemsHelper));
+    handlebars.register_escape_fn(handlebars::no_escape);
+
+    handlebars
+}
+
+#[test]
+fn items() {
+    let handlebars = handlebars();
+
+    assert_eq!(
+        "prev:/items?page=9 page:/items?page=1 ignore: page:/items?page=9 current_page:/items?page=10 page:/items?page=11 ignore: page:/items?page=20 next:/items?page=11 ",
+        handlebars
+            .render_template(
+                r#"{{#each (page_items current_page=10 total_pages=20 base_url="/items")}}{{kind}}:{{href}} {{/each}}"#,
+                &json!({}),
+            )
+            .unwrap()
+    );
+
+    assert_eq!(
+        "« 1 2* 3 » ",
+        handlebars
+            .render_template(
+                r#"{{#each (page_items current_page=page total_pages=total)}}{{label}}{{#if is_current}}*{{/if}} {{/each}}"#,
+                &json!({ "page": 2, "total": 3 }),
+            )
+            .unwrap()
+    );
+}
+
+#[test]
+fn invalid_arguments() {
+    let handlebars = handlebars();
+
+    assert!(handlebars.render_template("{{#each (page_items)}}{{/each}}", &json!({})).is_err());
+    assert!(handlebars
+        .render_template("{{#each (page_items total_pages=3 current_page=0)}}{{/each}}", &json!({}))
+        .is_err());
+    assert!(handlebars
+        .render_template(
+            r#"{{#each (page_items total_pages=3 base_url="/items" page_param=1)}}{{/each}}"#,
+            &json!({})
+        )
+        .is_err());
+}
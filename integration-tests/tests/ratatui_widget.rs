@@ -0,0 +1,72 @@
+use paginator::{ratatui::PaginationBar, Paginator, PaginatorBuilder, YesNoDepends};
+use ratatui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    Terminal,
+};
+
+fn render(bar: &PaginationBar, width: u16) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(width, 1)).unwrap();
+
+    terminal.draw(|frame| frame.render_widget(bar, frame.area())).unwrap();
+
+    terminal.backend().buffer().clone()
+}
+
+#[test]
+fn render_styles() {
+    let paginator = PaginatorBuilder::new(20)
+        .current_page(1)
+        .has_prev(YesNoDepends::Yes)
+        .build_paginator()
+        .unwrap();
+
+    let buffer = render(&PaginationBar::new(&paginator), 30);
+
+    let mut expected = Buffer::with_lines(["« 1 2 3 4 5 … 20 »            "]);
+
+    expected.set_style(Rect::new(0, 0, 1, 1), Style::new().fg(Color::DarkGray));
+    expected.set_style(
+        Rect::new(2, 0, 1, 1),
+        Style::new().add_modifier(Modifier::REVERSED | Modifier::BOLD),
+    );
+    expected.set_style(Rect::new(12, 0, 1, 1), Style::new().add_modifier(Modifier::DIM));
+
+    assert_eq!(expected, buffer);
+}
+
+#[test]
+fn auto_shrink() {
+    let paginator = Paginator::builder(20).current_page(10).build_paginator().unwrap();
+
+    let bar =
+        PaginationBar::new(&paginator).current_style(Style::new()).ellipsis_style(Style::new());
+
+    assert_eq!(Buffer::with_lines(["« 1 … 9 10 11 … 20 »"]), render(&bar, 20));
+    assert_eq!(Buffer::with_lines(["« 1 … 10 … 20 »"]), render(&bar, 15));
+
+    // cannot shrink any further, so the bar is clipped
+    assert_eq!(Buffer::with_lines(["« 1 … 10"]), render(&bar, 8));
+}
+
+#[test]
+fn page_at() {
+    let paginator = Paginator::builder(20).current_page(10).build_paginator().unwrap();
+
+    let bar = PaginationBar::new(&paginator);
+    let area = Rect::new(5, 2, 20, 1);
+
+    // « 1 … 9 10 11 … 20 »
+    assert_eq!(Some(9), bar.page_at(area, 5, 2));
+    assert_eq!(None, bar.page_at(area, 6, 2));
+    assert_eq!(Some(1), bar.page_at(area, 7, 2));
+    assert_eq!(None, bar.page_at(area, 9, 2));
+    // the current page
+    assert_eq!(None, bar.page_at(area, 13, 2));
+    assert_eq!(None, bar.page_at(area, 14, 2));
+    assert_eq!(Some(11), bar.page_at(area, 24, 2));
+    assert_eq!(None, bar.page_at(area, 25, 2));
+    assert_eq!(None, bar.page_at(area, 13, 3));
+}
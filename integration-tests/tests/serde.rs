@@ -0,0 +1,133 @@
+use std::num::NonZeroUsize;
+
+use paginator::{JumpSteps, Layout, PageItem, Paginator, PaginatorBuilder, YesNoDepends};
+use serde_json::json;
+
+fn page(page: usize) -> NonZeroUsize {
+    NonZeroUsize::new(page).unwrap()
+}
+
+#[test]
+fn page_items() {
+    let items = [
+        (PageItem::Page(page(3)), json!({"type": "page", "page": 3})),
+        (PageItem::CurrentPage(page(4)), json!({"type": "current_page", "page": 4})),
+        (
+            PageItem::Ignore {
+                start: page(5), end: page(9)
+            },
+            json!({"type": "ignore", "start": 5, "end": 9}),
+        ),
+        (
+            PageItem::More {
+                start: page(11)
+            },
+            json!({"type": "more", "start": 11}),
+        ),
+        (PageItem::Prev(page(3)), json!({"type": "prev", "page": 3})),
+        (PageItem::ReservedNext, json!({"type": "reserved_next"})),
+        (PageItem::Placeholder, json!({"type": "placeholder"})),
+        (
+            PageItem::JumpForward {
+                page: page(14), step: 10
+            },
+            json!({"type": "jump_forward", "page": 14, "step": 10}),
+        ),
+        (
+            PageItem::ReservedJumpBackward {
+                step: 10
+            },
+            json!({"type": "reserved_jump_backward", "step": 10}),
+        ),
+    ];
+
+    for (item, value) in items {
+        assert_eq!(value, serde_json::to_value(item).unwrap());
+        assert_eq!(item, serde_json::from_value(value).unwrap());
+    }
+
+    assert!(serde_json::from_value::<PageItem>(json!({"type": "page", "page": 0})).is_err());
+    assert!(serde_json::from_value::<PageItem>(json!({"type": "unknown"})).is_err());
+    assert!(serde_json::from_value::<PageItem>(json!({"type": "ignore", "start": 9, "end": 5}))
+        .is_err());
+}
+
+#[test]
+fn settings() {
+    assert_eq!(json!("depends"), serde_json::to_value(YesNoDepends::Depends).unwrap());
+    assert_eq!(json!("logarithmic"), serde_json::to_value(Layout::Logarithmic).unwrap());
+    assert_eq!(json!([10, 100]), serde_json::to_value(JumpSteps::from_slice(&[100, 10])).unwrap());
+    assert_eq!(
+        JumpSteps::from_slice(&[10, 100]),
+        serde_json::from_value(json!([100, 0, 10, 100])).unwrap()
+    );
+}
+
+#[test]
+fn paginator_round_trip() {
+    let paginator = Paginator::builder(20)
+        .current_page(7)
+        .max_item_count(11)
+        .has_first(YesNoDepends::Depends)
+        .jump_steps(&[5])
+        .layout(Layout::Logarithmic)
+        .build_paginator()
+        .unwrap();
+
+    let value = serde_json::to_value(&paginator).unwrap();
+
+    assert_eq!(json!(7), value["current_page"]);
+    assert_eq!(json!("depends"), value["has_first"]);
+    assert_eq!(json!([5]), value["jump_steps"]);
+    assert_eq!(paginator, serde_json::from_value(value).unwrap());
+
+    let builder = PaginatorBuilder::from_items(95, 10).current_page(3);
+
+    assert_eq!(builder, serde_json::from_value(serde_json::to_value(&builder).unwrap()).unwrap());
+}
+
+#[test]
+fn invalid_paginator() {
+    let mut value =
+        serde_json::to_value(Paginator::builder(20).build_paginator().unwrap()).unwrap();
+
+    value["current_page"] = json!(21);
+
+    let error = serde_json::from_value::<Paginator>(value.clone()).unwrap_err();
+
+    assert!(error.to_string().contains("current_page > total_pages"));
+
+    value["current_page"] = json!(1);
+    value["max_item_count"] = json!(2);
+
+    assert!(serde_json::from_value::<Paginator>(value.clone()).is_err());
+
+    // A builder can hold invalid settings.
+    assert!(serde_json::from_value::<PaginatorBuilder>(value).is_ok());
+}
+
+#[test]
+fn links_and_meta() {
+    let paginator = PaginatorBuilder::from_items(25, 10).current_page(3).build_paginator().unwrap();
+    let links = paginator.links_with_base_url("/items", "page");
+
+    for json in
+        [links.json_api().to_string(), links.hal().to_string(), paginator.meta().to_string()]
+    {
+        assert!(serde_json::from_str::<serde_json::Value>(&json).is_ok());
+    }
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&links.json_api().to_string()).unwrap(),
+        serde_json::to_value(links.json_api()).unwrap()
+    );
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&links.hal().to_string()).unwrap(),
+        serde_json::to_value(links.hal()).unwrap()
+    );
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&paginator.meta().to_string()).unwrap(),
+        serde_json::to_value(paginator.meta()).unwrap()
+    );
+    assert_eq!(json!("/items?page=2"), serde_json::to_value(links.prev.unwrap()).unwrap());
+}
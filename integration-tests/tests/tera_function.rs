@@ -0,0 +1,47 @@
+use paginator::tera::PageItemsFunction;
+use tera::{Context, Tera};
+
+fn render(template: &str) -> tera::Result<String> {
+    let mut tera = Tera::default();
+
+    tera.register_function("page_items", PageItemsFunction);
+    tera.add_raw_template("pagination", template)?;
+    tera.autoescape_on(vec![]);
+
+    tera.render("pagination", &Context::new())
+}
+
+#[test]
+fn items() {
+    assert_eq!(
+        "prev:/items/9 page:/items/1 ignore: page:/items/9 current_page:/items/10 page:/items/11 ignore: page:/items/20 next:/items/11",
+        render(
+            r#"{% for item in page_items(current_page=10, total_pages=20, url="/items/{page}") %}{% if not loop.first %} {% endif %}{{ item.kind }}:{{ item.href }}{% endfor %}"#
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        "« 1 2* 3 »",
+        render(
+            r#"{% for item in page_items(current_page=2, total_pages=3) %}{% if not loop.first %} {% endif %}{{ item.label }}{% if item.is_current %}*{% endif %}{% endfor %}"#
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        "/search?q=a&p=2 /search?q=a&p=1",
+        render(
+            r#"{% set items = page_items(current_page=1, total_pages=9, max_item_count=7, base_url="/search?q=a&p=4", page_param="p") %}{{ items | last | get(key="href") }} {{ items | first | get(key="href") }}"#
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn invalid_arguments() {
+    assert!(render("{{ page_items() }}").is_err());
+    assert!(render("{{ page_items(total_pages=3, current_page=4) }}").is_err());
+    assert!(render(r#"{{ page_items(total_pages="3") }}"#).is_err());
+    assert!(render(r#"{{ page_items(total_pages=3, base_url="/items", page_param=1) }}"#).is_err());
+}
@@ -0,0 +1,122 @@
+/*!
+[actix-web](https://docs.rs/actix-web) integration.
+
+`Pagination` can be extracted from a request, reading `page` and `per_page` from the query string with `PageQuery`. The settings can be changed by adding a `PageQuery` with `App::app_data`. Otherwise, `PageQuery::new()` is used. Invalid parameters are rejected with `400 Bad Request` and a JSON body.
+
+`Paginated` wraps any responder to add the `Link` and `X-Total-Count` headers of a `Paginator`. The URLs of the `Link` header are made by setting the page parameter of the request URL.
+
+```rust
+use actix_web::{web, App, Responder};
+use paginator::{
+    actix_web::{Paginated, Pagination, PaginationRejection},
+    PageQuery,
+};
+
+async fn list(pagination: Pagination) -> Result<impl Responder, PaginationRejection> {
+    let total_items = 95; // usually counted from the database
+
+    let paginator = pagination.paginator(total_items)?;
+
+    Ok(Paginated::new(format!("page {}", paginator.current_page()), paginator))
+}
+
+let app = App::new()
+    .app_data(PageQuery::new().max_per_page(50))
+    .route("/items", web::get().to(list));
+```
+*/
+
+use std::future::{ready, Ready};
+
+use ::actix_web::{
+    dev::Payload,
+    http::{
+        header::{HeaderName, HeaderValue, LINK},
+        StatusCode,
+    },
+    FromRequest, HttpRequest, HttpResponse, Responder, ResponseError,
+};
+
+use crate::{PageQuery, PageUrl, Paginator};
+pub use crate::{Pagination, PaginationRejection};
+
+/// Return the `PageQuery` added with `App::app_data`, or the default one.
+#[inline]
+fn page_query(req: &HttpRequest) -> PageQuery<'static> {
+    req.app_data::<PageQuery<'static>>().copied().unwrap_or_default()
+}
+
+impl FromRequest for Pagination {
+    type Error = PaginationRejection;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    #[inline]
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(page_query(req).pagination(req.query_string()).map_err(PaginationRejection::from))
+    }
+}
+
+impl ResponseError for PaginationRejection {
+    #[inline]
+    fn status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+
+    #[inline]
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code())
+            .content_type("application/json")
+            .body(self.to_json())
+    }
+}
+
+/// A responder which adds the `Link` header and, if the number of items is known, the `X-Total-Count` header of a `Paginator` to the response of the inner responder.
+#[derive(Debug, Clone)]
+pub struct Paginated<R> {
+    inner:     R,
+    paginator: Paginator,
+}
+
+impl<R> Paginated<R> {
+    /// Wrap a responder.
+    #[inline]
+    pub const fn new(inner: R, paginator: Paginator) -> Paginated<R> {
+        Paginated {
+            inner,
+            paginator,
+        }
+    }
+
+    /// Return the inner responder.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Responder> Responder for Paginated<R> {
+    type Body = R::Body;
+
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+        let mut response = self.inner.respond_to(req);
+
+        let base_url = req.uri().path_and_query().map_or_else(|| req.path(), |url| url.as_str());
+        let page_url = PageUrl::new(base_url).page_param(page_query(req).page_param);
+
+        let link_header = self.paginator.links_with_page_url(page_url).link_header().to_string();
+
+        let headers = response.headers_mut();
+
+        // The URLs are percent-encoded, so the value is always valid.
+        if let Ok(value) = HeaderValue::from_str(&link_header) {
+            headers.insert(LINK, value);
+        }
+
+        if let Some(total_items) = self.paginator.total_items() {
+            headers
+                .insert(HeaderName::from_static("x-total-count"), HeaderValue::from(total_items));
+        }
+
+        response
+    }
+}
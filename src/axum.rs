@@ -0,0 +1,48 @@
+/*!
+An [axum](https://docs.rs/axum) extractor for the pagination parameters of a request.
+
+`Pagination` reads `page` and `per_page` from the query string with `PageQuery`. The settings can be changed by adding a `PageQuery` to the request extensions, usually with an `Extension` layer such as `.layer(Extension(PageQuery::new().max_per_page(50)))`. Otherwise, `PageQuery::new()` is used.
+
+```rust
+use paginator::axum::{Pagination, PaginationRejection};
+
+async fn list(pagination: Pagination) -> Result<String, PaginationRejection> {
+    let total_items = 95; // usually counted from the database
+
+    let paginator = pagination.paginator(total_items)?;
+
+    Ok(format!("page {} of {}", paginator.current_page(), paginator.total_pages()))
+}
+```
+*/
+
+use axum_core::{
+    extract::FromRequestParts,
+    response::{IntoResponse, Response},
+};
+use http::{header, request::Parts, HeaderValue, StatusCode};
+
+use crate::PageQuery;
+pub use crate::{Pagination, PaginationRejection};
+
+impl<S: Send + Sync> FromRequestParts<S> for Pagination {
+    type Rejection = PaginationRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let query = parts.extensions.get::<PageQuery<'static>>().copied().unwrap_or_default();
+
+        Ok(query.pagination(parts.uri.query().unwrap_or(""))?)
+    }
+}
+
+impl IntoResponse for PaginationRejection {
+    #[inline]
+    fn into_response(self) -> Response {
+        (
+            StatusCode::BAD_REQUEST,
+            [(header::CONTENT_TYPE, HeaderValue::from_static("application/json"))],
+            self.to_json(),
+        )
+            .into_response()
+    }
+}
@@ -0,0 +1,90 @@
+//! The shared logic of the UI framework components, which render the same markup with different frameworks.
+//!
+//! Each `PageItem` is rendered as `<li class="{kind}">`, where `kind` is `PageItem::kind`. Ellipses and placeholders contain an `aria-hidden` `<span>`, and the other items contain a `<button>`. The `<nav>` element handles the arrow keys, `PageUp`, `PageDown`, `Home` and `End` while focus is on one of the buttons, with `page_for_key`.
+
+use crate::{render::html::HtmlLabels, PageItem, Paginator};
+
+/// The `aria-label` of the `<nav>` element.
+pub(crate) const NAV_ARIA: &str = HtmlLabels::new().nav_aria;
+
+/// Whether the item is rendered as a button.
+#[inline]
+pub(crate) fn is_button(item: &PageItem) -> bool {
+    !item.is_ellipsis() && *item != PageItem::Placeholder
+}
+
+/// Return the page to change to when the button of the item is clicked.
+#[inline]
+pub(crate) fn click_target(item: &PageItem) -> Option<usize> {
+    if item.is_current() {
+        None
+    } else {
+        item.page().map(|page| page.get())
+    }
+}
+
+/// Return the `aria-label` of the button of the item.
+pub(crate) fn aria_label(item: &PageItem) -> Option<String> {
+    let labels = HtmlLabels::new();
+
+    let label = match item {
+        PageItem::Page(page) | PageItem::CurrentPage(page) => {
+            format!("{} {}", labels.page_aria, page)
+        },
+        PageItem::Prev(_) | PageItem::ReservedPrev => labels.prev_aria.to_string(),
+        PageItem::Next(_) | PageItem::ReservedNext => labels.next_aria.to_string(),
+        PageItem::First(_) | PageItem::ReservedFirst => labels.first_aria.to_string(),
+        PageItem::Last(_) | PageItem::ReservedLast => labels.last_aria.to_string(),
+        PageItem::JumpBackward {
+            step, ..
+        }
+        | PageItem::ReservedJumpBackward {
+            step,
+        } => format!("{} {}", labels.jump_backward_aria, step),
+        PageItem::JumpForward {
+            step, ..
+        }
+        | PageItem::ReservedJumpForward {
+            step,
+        } => format!("{} {}", labels.jump_forward_aria, step),
+        _ => return None,
+    };
+
+    Some(label)
+}
+
+/**
+    Return the page to change to when a key is pressed on a pagination bar, for keyboard navigation. The key is a `KeyboardEvent.key` value of the DOM.
+
+    The left and up arrow keys and `PageUp` go to the previous page, the right and down arrow keys and `PageDown` go to the next page, and `Home` and `End` go to the first and the last page. `None` is returned if the page does not change.
+
+    ```rust
+    # use paginator::{component::page_for_key, Paginator};
+    let paginator = Paginator::builder(5).current_page(5).build_paginator().unwrap();
+
+    assert_eq!(Some(4), page_for_key(&paginator, "ArrowLeft"));
+    assert_eq!(None, page_for_key(&paginator, "ArrowRight"));
+    assert_eq!(Some(1), page_for_key(&paginator, "Home"));
+    ```
+*/
+pub fn page_for_key(paginator: &Paginator, key: &str) -> Option<usize> {
+    let current_page = paginator.current_page;
+
+    let page = match key {
+        "ArrowLeft" | "ArrowUp" | "PageUp" if current_page > 1 => current_page - 1,
+        "ArrowRight" | "ArrowDown" | "PageDown"
+            if current_page < paginator.total_pages || paginator.has_more =>
+        {
+            current_page + 1
+        },
+        "Home" => 1,
+        "End" if !paginator.has_more => paginator.total_pages,
+        _ => return None,
+    };
+
+    if page == current_page {
+        None
+    } else {
+        Some(page)
+    }
+}
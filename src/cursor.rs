@@ -0,0 +1,332 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::{self, Display, Formatter, Write},
+    iter::FusedIterator,
+};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::YesNoDepends;
+
+/// An item displayed in a cursor-based pagination bar.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CursorItem<C> {
+    /// A link to the previous page, which ends before the cursor.
+    Prev(C),
+    /// A link to the next page, which starts after the cursor.
+    Next(C),
+    /// A reserved previous-page position without a valid target.
+    ReservedPrev,
+    /// A reserved next-page position without a valid target.
+    ReservedNext,
+}
+
+impl<C> CursorItem<C> {
+    /// Return the cursor of the item, if any.
+    #[inline]
+    pub fn cursor(&self) -> Option<&C> {
+        match self {
+            CursorItem::Prev(cursor) | CursorItem::Next(cursor) => Some(cursor),
+            CursorItem::ReservedPrev | CursorItem::ReservedNext => None,
+        }
+    }
+}
+
+impl<C> Display for CursorItem<C> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            CursorItem::Prev(_) => f.write_str("<"),
+            CursorItem::Next(_) => f.write_str(">"),
+            CursorItem::ReservedPrev => f.write_str(")"),
+            CursorItem::ReservedNext => f.write_str("("),
+        }
+    }
+}
+
+/**
+    Pagination settings for one page of a cursor-based (keyset) list, which only knows the neighbouring pages by their opaque cursors.
+
+    ```rust
+    use paginator::{CursorItem, CursorPaginator, YesNoDepends};
+
+    let paginator = CursorPaginator::new().after("id:42").has_prev(YesNoDepends::Yes);
+
+    assert_eq!(
+        vec![CursorItem::ReservedPrev, CursorItem::Next(&"id:42")],
+        paginator.items().collect::<Vec<_>>()
+    );
+    ```
+*/
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CursorPaginator<C> {
+    pub(crate) before:   Option<C>,
+    pub(crate) after:    Option<C>,
+    pub(crate) has_prev: YesNoDepends,
+    pub(crate) has_next: YesNoDepends,
+}
+
+impl<C> CursorPaginator<C> {
+    /// Create a new `CursorPaginator` without cursors. The previous-page and next-page items are added only if their cursors are set.
+    #[inline]
+    pub const fn new() -> CursorPaginator<C> {
+        CursorPaginator {
+            before:   None,
+            after:    None,
+            has_prev: YesNoDepends::Depends,
+            has_next: YesNoDepends::Depends,
+        }
+    }
+
+    /// Set the cursor of the previous page, such as the key of the first item on this page.
+    #[inline]
+    pub fn before(mut self, cursor: C) -> CursorPaginator<C> {
+        self.before = Some(cursor);
+
+        self
+    }
+
+    /// Set the cursor of the next page, such as the key of the last item on this page.
+    #[inline]
+    pub fn after(mut self, cursor: C) -> CursorPaginator<C> {
+        self.after = Some(cursor);
+
+        self
+    }
+
+    /// Set whether to add the `CursorItem::Prev` item. `YesNoDepends::Yes` adds a `CursorItem::ReservedPrev` item if there is no previous page.
+    #[inline]
+    pub fn has_prev(mut self, has_prev: YesNoDepends) -> CursorPaginator<C> {
+        self.has_prev = has_prev;
+
+        self
+    }
+
+    /// Set whether to add the `CursorItem::Next` item. `YesNoDepends::Yes` adds a `CursorItem::ReservedNext` item if there is no next page.
+    #[inline]
+    pub fn has_next(mut self, has_next: YesNoDepends) -> CursorPaginator<C> {
+        self.has_next = has_next;
+
+        self
+    }
+}
+
+impl<C> CursorPaginator<C> {
+    /// Return the cursor of the previous page, if any.
+    #[inline]
+    pub fn before_cursor(&self) -> Option<&C> {
+        self.before.as_ref()
+    }
+
+    /// Return the cursor of the next page, if any.
+    #[inline]
+    pub fn after_cursor(&self) -> Option<&C> {
+        self.after.as_ref()
+    }
+
+    /// Create an iterator which yields the `CursorItem`s, borrowing the cursors.
+    #[inline]
+    pub fn items(&self) -> CursorItems<'_, C> {
+        let prev = match (&self.before, self.has_prev) {
+            (_, YesNoDepends::No) => None,
+            (Some(cursor), _) => Some(CursorItem::Prev(cursor)),
+            (None, YesNoDepends::Yes) => Some(CursorItem::ReservedPrev),
+            (None, YesNoDepends::Depends) => None,
+        };
+
+        let next = match (&self.after, self.has_next) {
+            (_, YesNoDepends::No) => None,
+            (Some(cursor), _) => Some(CursorItem::Next(cursor)),
+            (None, YesNoDepends::Yes) => Some(CursorItem::ReservedNext),
+            (None, YesNoDepends::Depends) => None,
+        };
+
+        CursorItems {
+            prev,
+            next,
+        }
+    }
+}
+
+impl<C> Default for CursorPaginator<C> {
+    #[inline]
+    fn default() -> Self {
+        CursorPaginator::new()
+    }
+}
+
+/// An iterator which yields the `CursorItem`s of a `CursorPaginator`. Created by `CursorPaginator::items`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CursorItems<'a, C> {
+    prev: Option<CursorItem<&'a C>>,
+    next: Option<CursorItem<&'a C>>,
+}
+
+impl<'a, C> Iterator for CursorItems<'a, C> {
+    type Item = CursorItem<&'a C>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.prev.take().or_else(|| self.next.take())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.prev.is_some() as usize + self.next.is_some() as usize;
+        (len, Some(len))
+    }
+}
+
+impl<C> ExactSizeIterator for CursorItems<'_, C> {}
+
+impl<C> FusedIterator for CursorItems<'_, C> {}
+
+impl<C> DoubleEndedIterator for CursorItems<'_, C> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next.take().or_else(|| self.prev.take())
+    }
+}
+
+const BASE64_URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/**
+    A cursor formatted as a URL-safe base64 token without padding, so any bytes (such as a serialized sort key) can be put in a query string.
+
+    ```rust
+    # use paginator::CursorToken;
+    assert_eq!("aWQ6NDI", CursorToken::new(b"id:42").to_string());
+    ```
+*/
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct CursorToken<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> CursorToken<'a> {
+    /// Create a `CursorToken` for the cursor bytes.
+    #[inline]
+    pub const fn new(bytes: &'a [u8]) -> CursorToken<'a> {
+        CursorToken {
+            bytes,
+        }
+    }
+}
+
+impl Display for CursorToken<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        for chunk in self.bytes.chunks(3) {
+            let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+            let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+
+            // Every 3 bytes become 4 characters, and a partial chunk of n bytes becomes n + 1 characters.
+            for i in 0..=chunk.len() {
+                f.write_char(BASE64_URL_ALPHABET[(n >> (18 - 6 * i) & 0x3F) as usize] as char)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when a cursor token is not valid URL-safe base64.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CursorTokenError {
+    /// The character at `index` is not in the URL-safe base64 alphabet, or has unused bits set.
+    InvalidCharacter { index: usize },
+    /// The length of the token is not a valid base64 length.
+    InvalidLength,
+}
+
+impl Display for CursorTokenError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            CursorTokenError::InvalidCharacter {
+                index,
+            } => f.write_fmt(format_args!("invalid base64 character at index {}", index)),
+            CursorTokenError::InvalidLength => f.write_str("invalid base64 length"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for CursorTokenError {}
+
+/// Encode cursor bytes to a URL-safe base64 token without padding. See `CursorToken`.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[inline]
+pub fn encode_cursor<T: AsRef<[u8]>>(cursor: T) -> String {
+    let mut s = String::new();
+
+    s.write_fmt(format_args!("{}", CursorToken::new(cursor.as_ref()))).unwrap();
+
+    s
+}
+
+/**
+    Decode a URL-safe base64 token, with or without padding, to cursor bytes.
+
+    ```rust
+    # use paginator::{decode_cursor, encode_cursor, CursorTokenError};
+    assert_eq!(Ok(b"id:42".to_vec()), decode_cursor(&encode_cursor("id:42")));
+    assert_eq!(Err(CursorTokenError::InvalidCharacter { index: 2 }), decode_cursor("aW+6"));
+    ```
+*/
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn decode_cursor(token: &str) -> Result<Vec<u8>, CursorTokenError> {
+    let token = token.as_bytes();
+
+    let token = if token.len() % 4 == 0 {
+        match token {
+            [rest @ .., b'=', b'='] | [rest @ .., b'='] => rest,
+            _ => token,
+        }
+    } else {
+        token
+    };
+
+    if token.len() % 4 == 1 {
+        return Err(CursorTokenError::InvalidLength);
+    }
+
+    let mut bytes = Vec::with_capacity(token.len() / 4 * 3 + 2);
+
+    for (chunk_index, chunk) in token.chunks(4).enumerate() {
+        let mut n = 0u32;
+
+        for (i, &c) in chunk.iter().enumerate() {
+            let value = match c {
+                b'A'..=b'Z' => c - b'A',
+                b'a'..=b'z' => c - b'a' + 26,
+                b'0'..=b'9' => c - b'0' + 52,
+                b'-' => 62,
+                b'_' => 63,
+                _ => {
+                    return Err(CursorTokenError::InvalidCharacter {
+                        index: chunk_index * 4 + i
+                    })
+                },
+            };
+
+            n |= (value as u32) << (18 - 6 * i);
+        }
+
+        let byte_count = chunk.len() - 1;
+
+        // Reject the unused low bits of a partial chunk, so every cursor has exactly one token.
+        if n & (0xFF_FFFF >> (8 * byte_count)) != 0 {
+            return Err(CursorTokenError::InvalidCharacter {
+                index: chunk_index * 4 + byte_count
+            });
+        }
+
+        bytes.extend_from_slice(&n.to_be_bytes()[1..=byte_count]);
+    }
+
+    Ok(bytes)
+}
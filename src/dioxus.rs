@@ -0,0 +1,67 @@
+/*!
+A [Dioxus](https://docs.rs/dioxus) component which renders a pagination bar.
+
+```rust
+use dioxus::prelude::*;
+use paginator::{dioxus::PaginationBar, Paginator};
+
+fn app(paginator: Paginator) -> Element {
+    rsx! {
+        PaginationBar { paginator, on_change: |page: usize| println!("go to page {}", page) }
+    }
+}
+
+let mut dom = VirtualDom::new_with_props(app, Paginator::builder(3).current_page(2).build_paginator().unwrap());
+
+dom.rebuild_in_place();
+```
+*/
+
+use ::dioxus::prelude::*;
+
+use crate::{
+    component::{aria_label, click_target, is_button, page_for_key, NAV_ARIA},
+    Paginator,
+};
+
+/// Render the items of `paginator` as buttons, calling `on_change` with the page number when a page is chosen by a click or a key.
+#[component]
+pub fn PaginationBar(paginator: Paginator, on_change: EventHandler<usize>) -> Element {
+    let items = paginator.paginate();
+
+    let onkeydown = move |event: KeyboardEvent| {
+        if let Some(page) = page_for_key(&paginator, &event.key().to_string()) {
+            event.prevent_default();
+            on_change.call(page);
+        }
+    };
+
+    rsx! {
+        nav { aria_label: NAV_ARIA, onkeydown,
+            ul { class: "pagination",
+                for item in items {
+                    if is_button(&item) {
+                        li { class: item.kind(),
+                            button {
+                                r#type: "button",
+                                aria_label: aria_label(&item),
+                                aria_current: if item.is_current() { Some("page") } else { None },
+                                disabled: item.is_disabled(),
+                                onclick: move |_| {
+                                    if let Some(page) = click_target(&item) {
+                                        on_change.call(page);
+                                    }
+                                },
+                                "{item.label()}"
+                            }
+                        }
+                    } else {
+                        li { class: item.kind(),
+                            span { aria_hidden: "true", "{item.label()}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
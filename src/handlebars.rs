@@ -0,0 +1,52 @@
+/*!
+A [Handlebars](https://docs.rs/handlebars) helper which creates the page items of a pagination bar, so templates can loop over them without matching `PageItem` variants.
+
+The helper returns a list to be used as a subexpression. It takes the hash arguments `current_page` (defaults to 1), `total_pages`, `max_item_count` (optional), and either `url` (a `UrlTemplate` such as `"/items?page={page}"`) or `base_url` with an optional `page_param` (a `PageUrl`). Each item is an object with the fields `kind` (see `PageItem::kind`), `page`, `label` (see `PageItem::label`), `href`, `is_current`, `is_disabled` and `is_ellipsis`. The `page` and `href` fields are `null` if the item does not link to a page.
+
+```rust
+use handlebars::Handlebars;
+use paginator::handlebars::PageItemsHelper;
+
+let mut handlebars = Handlebars::new();
+
+handlebars.register_helper("page_items", Box::new(PageItemsHelper));
+
+let html = handlebars
+    .render_template(
+        r#"{{#each (page_items current_page=2 total_pages=3 url="/items/{page}")}}{{#if is_current}}<span>{{label}}</span>{{else if href}}<a href="{{href}}">{{label}}</a>{{else}}<span>{{label}}</span>{{/if}}{{/each}}"#,
+        &(),
+    )
+    .unwrap();
+
+assert_eq!(
+    r#"<a href="/items/1">«</a><a href="/items/1">1</a><span>2</span><a href="/items/3">3</a><a href="/items/3">»</a>"#,
+    html
+);
+```
+*/
+
+use ::handlebars::{
+    Context, Handlebars, Helper, HelperDef, RenderContext, RenderError, RenderErrorReason,
+    ScopedJson,
+};
+
+use crate::template::page_items;
+
+/// The Handlebars helper which creates page items. See the module documentation.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PageItemsHelper;
+
+impl HelperDef for PageItemsHelper {
+    #[inline]
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _: &'reg Handlebars<'reg>,
+        _: &'rc Context,
+        _: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'rc>, RenderError> {
+        page_items(|name| h.hash_get(name).map(|value| value.value()))
+            .map(ScopedJson::Derived)
+            .map_err(|error| RenderErrorReason::Other(error).into())
+    }
+}
@@ -0,0 +1,101 @@
+use core::ops::Deref;
+
+/// Distances of the jump items (such as "-10" and "+10") on a pagination bar.
+///
+/// At most `JumpSteps::MAX_LEN` steps are kept. They are sorted in ascending order without duplicates, and zero steps are ignored.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct JumpSteps {
+    steps: [usize; JumpSteps::MAX_LEN],
+    len:   usize,
+}
+
+impl JumpSteps {
+    /// The max number of steps.
+    pub const MAX_LEN: usize = 4;
+
+    /// Create an empty `JumpSteps`.
+    #[inline]
+    pub const fn new() -> JumpSteps {
+        JumpSteps {
+            steps: [0; JumpSteps::MAX_LEN], len: 0
+        }
+    }
+
+    /**
+        Create a `JumpSteps` from a slice. Steps after the first `JumpSteps::MAX_LEN` distinct non-zero steps are dropped.
+
+        ```rust
+        # use paginator::JumpSteps;
+        assert_eq!(&[10, 100], JumpSteps::from_slice(&[100, 0, 10, 100]).as_slice());
+        ```
+    */
+    pub const fn from_slice(steps: &[usize]) -> JumpSteps {
+        let mut jump_steps = JumpSteps::new();
+
+        let mut i = 0;
+
+        while i < steps.len() {
+            jump_steps = jump_steps.insert(steps[i]);
+
+            i += 1;
+        }
+
+        jump_steps
+    }
+
+    /// Insert a step in order. Nothing is inserted if the step is zero, a duplicate, or there are already `JumpSteps::MAX_LEN` steps.
+    pub(crate) const fn insert(mut self, step: usize) -> JumpSteps {
+        if step == 0 || self.len == JumpSteps::MAX_LEN {
+            return self;
+        }
+
+        // Insertion sort, skipping duplicates.
+        let mut j = self.len;
+
+        while j > 0 && self.steps[j - 1] > step {
+            j -= 1;
+        }
+
+        if j > 0 && self.steps[j - 1] == step {
+            return self;
+        }
+
+        let mut k = self.len;
+
+        while k > j {
+            self.steps[k] = self.steps[k - 1];
+            k -= 1;
+        }
+
+        self.steps[j] = step;
+        self.len += 1;
+
+        self
+    }
+
+    /// Extract a slice containing the steps in ascending order.
+    #[inline]
+    pub fn as_slice(&self) -> &[usize] {
+        &self.steps[..self.len]
+    }
+
+    /// Keep only the steps which are smaller than `total_pages`, because a larger step always reaches the first or last page.
+    #[inline]
+    pub(crate) fn effective(mut self, total_pages: usize) -> JumpSteps {
+        while self.len > 0 && self.steps[self.len - 1] >= total_pages {
+            self.len -= 1;
+            self.steps[self.len] = 0;
+        }
+
+        self
+    }
+}
+
+impl Deref for JumpSteps {
+    type Target = [usize];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
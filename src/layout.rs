@@ -0,0 +1,31 @@
+/// The algorithm which chooses the page links of a pagination bar.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Layout {
+    /**
+        Show a contiguous window around the current page, plus `start_size` and `end_size` pages on the edges.
+
+        ```text
+        < 1 ... 97 98 99 100* 101 102 103 ... 1000 >
+        ```
+    */
+    Window,
+    /**
+        Show pages at growing distances (1, 2, 5, 10, 20, 50, ...) from the current page, plus the first and the last page, so far pages can be reached in a few clicks. The `start_size` and `end_size` options are not used.
+
+        With `max_item_count` set to 25:
+
+        ```text
+        < 1 ... 50 ... 80 ... 90 ... 95 ... 98 99 100* 101 102 ... 105 ... 110 ... 120 ... 1000 >
+        ```
+    */
+    Logarithmic,
+}
+
+impl Default for Layout {
+    #[inline]
+    fn default() -> Self {
+        Layout::Window
+    }
+}
@@ -0,0 +1,307 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    fmt::{self, Display, Formatter},
+    iter,
+};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{page_items::ItemLayout, Layout, PageItem, PageItems, Paginator};
+
+/**
+    An algorithm which chooses the page links of a pagination bar.
+
+    A strategy only lays out the pages between the control items. The `First`, `Prev`, jump, `Next` and `Last` items are added around them according to the `Paginator` settings, so `max_item_count` is the number of items left for the pages after those controls.
+
+    A strategy emits `PageItem::Page`, `PageItem::CurrentPage` and `PageItem::Ignore` items in ascending page order, starting from page 1 without gaps, so every item starts right after the previous one. A trailing `PageItem::Ignore` item hides the pages up to the last page. Use `Paginator::paginate_with` to run it, which checks the output.
+
+    ```rust
+    # #[cfg(feature = "alloc")] {
+    use std::num::NonZeroUsize;
+
+    use paginator::{page_items_to_string, LayoutStrategy, PageItem, Paginator};
+
+    /// Show only the current page, and hide the pages around it.
+    struct CurrentOnly;
+
+    impl LayoutStrategy for CurrentOnly {
+        fn layout(
+            &self,
+            paginator: &Paginator,
+            _max_item_count: usize,
+            emit: &mut dyn FnMut(PageItem),
+        ) {
+            let current_page = paginator.current_page();
+            let total_pages = paginator.total_pages();
+
+            let page = |page| NonZeroUsize::new(page).unwrap();
+
+            if current_page > 1 {
+                emit(PageItem::Ignore { start: page(1), end: page(current_page - 1) });
+            }
+
+            emit(PageItem::CurrentPage(page(current_page)));
+
+            if current_page < total_pages {
+                emit(PageItem::Ignore { start: page(current_page + 1), end: page(total_pages) });
+            }
+        }
+    }
+
+    let paginator = Paginator::builder(20).current_page(5).build_paginator().unwrap();
+
+    assert_eq!("< ... 5* ... >", page_items_to_string(&paginator.paginate_with(&CurrentOnly).unwrap()));
+    # }
+    ```
+*/
+pub trait LayoutStrategy {
+    /// Emit the page items of `paginator` by calling `emit`, using at most `max_item_count` items.
+    fn layout(&self, paginator: &Paginator, max_item_count: usize, emit: &mut dyn FnMut(PageItem));
+}
+
+impl LayoutStrategy for Layout {
+    #[inline]
+    fn layout(&self, paginator: &Paginator, max_item_count: usize, emit: &mut dyn FnMut(PageItem)) {
+        let mut layout = ItemLayout::new(paginator.total_pages, paginator.current_page);
+
+        paginator.layout_pages(*self, &mut layout, max_item_count);
+
+        PageItems::new(layout).for_each(emit);
+    }
+}
+
+impl<S: LayoutStrategy + ?Sized> LayoutStrategy for &S {
+    #[inline]
+    fn layout(&self, paginator: &Paginator, max_item_count: usize, emit: &mut dyn FnMut(PageItem)) {
+        (**self).layout(paginator, max_item_count, emit)
+    }
+}
+
+/// An error returned when the output of a `LayoutStrategy` is invalid.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LayoutError {
+    /// The strategy emitted more items than `max_item_count` allows.
+    TooManyItems { max_item_count: usize },
+    /// The strategy emitted a page which is greater than the total number of pages.
+    PageOutOfRange { page: usize, total_pages: usize },
+    /// The strategy emitted a page which is not after the previous page or hidden range.
+    PagesNotAscending { page: usize },
+    /// The pages from `start` to `end` (inclusive) are neither shown nor hidden, because of a gap before an item or a trailing ignore item which does not reach the last page.
+    MissingPages { start: usize, end: usize },
+    /// The strategy emitted a control item, or a page item whose current-page flag is wrong.
+    UnexpectedItem(PageItem),
+    /// The strategy did not emit the current page.
+    MissingCurrentPage,
+}
+
+impl Display for LayoutError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            LayoutError::TooManyItems {
+                max_item_count,
+            } => f.write_fmt(format_args!("more than {} items are laid out", max_item_count)),
+            LayoutError::PageOutOfRange {
+                page,
+                total_pages,
+            } => f.write_fmt(format_args!(
+                "{page} > {total_pages} (page > total_pages)",
+                page = page,
+                total_pages = total_pages
+            )),
+            LayoutError::PagesNotAscending {
+                page,
+            } => f.write_fmt(format_args!("page {} is not in ascending order", page)),
+            LayoutError::MissingPages {
+                start,
+                end,
+            } => f.write_fmt(format_args!(
+                "pages {start} to {end} are neither shown nor hidden",
+                start = start,
+                end = end
+            )),
+            LayoutError::UnexpectedItem(item) => {
+                f.write_fmt(format_args!("unexpected item {:?}", item))
+            },
+            LayoutError::MissingCurrentPage => f.write_str("the current page is not laid out"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for LayoutError {}
+
+/// Checks the items emitted by a `LayoutStrategy` one at a time.
+struct LayoutChecker {
+    total_pages:    usize,
+    current_page:   usize,
+    max_item_count: usize,
+    item_count:     usize,
+    /// The last page which is shown or hidden so far.
+    last_page:      usize,
+    /// Whether the last item is a `PageItem::Ignore` item.
+    ends_in_ignore: bool,
+    has_current:    bool,
+    error:          Option<LayoutError>,
+}
+
+impl LayoutChecker {
+    #[inline]
+    fn new(paginator: &Paginator, max_item_count: usize) -> LayoutChecker {
+        LayoutChecker {
+            total_pages: paginator.total_pages,
+            current_page: paginator.current_page,
+            max_item_count,
+            item_count: 0,
+            last_page: 0,
+            ends_in_ignore: false,
+            has_current: false,
+            error: None,
+        }
+    }
+
+    fn check(&mut self, item: PageItem) -> Result<(), LayoutError> {
+        self.item_count += 1;
+
+        if self.item_count > self.max_item_count {
+            return Err(LayoutError::TooManyItems {
+                max_item_count: self.max_item_count
+            });
+        }
+
+        let (start, end, is_ignore) = match item {
+            PageItem::Page(page) if page.get() != self.current_page => {
+                (page.get(), page.get(), false)
+            },
+            PageItem::CurrentPage(page) if page.get() == self.current_page => {
+                self.has_current = true;
+
+                (page.get(), page.get(), false)
+            },
+            PageItem::Ignore {
+                start,
+                end,
+            } if start <= end => (start.get(), end.get(), true),
+            _ => return Err(LayoutError::UnexpectedItem(item)),
+        };
+
+        if start <= self.last_page {
+            return Err(LayoutError::PagesNotAscending {
+                page: start
+            });
+        }
+
+        if end > self.total_pages {
+            return Err(LayoutError::PageOutOfRange {
+                page:        end,
+                total_pages: self.total_pages,
+            });
+        }
+
+        if start > self.last_page + 1 {
+            return Err(LayoutError::MissingPages {
+                start: self.last_page + 1, end: start - 1
+            });
+        }
+
+        self.last_page = end;
+        self.ends_in_ignore = is_ignore;
+
+        Ok(())
+    }
+
+    /// Check `item` and return whether it is valid, remembering the first error.
+    #[inline]
+    fn accept(&mut self, item: PageItem) -> bool {
+        if self.error.is_some() {
+            return false;
+        }
+
+        match self.check(item) {
+            Ok(()) => true,
+            Err(error) => {
+                self.error = Some(error);
+
+                false
+            },
+        }
+    }
+
+    #[inline]
+    fn finish(self) -> Result<(), LayoutError> {
+        match self.error {
+            Some(error) => Err(error),
+            None if !self.has_current => Err(LayoutError::MissingCurrentPage),
+            None if self.ends_in_ignore && self.last_page < self.total_pages => {
+                Err(LayoutError::MissingPages {
+                    start: self.last_page + 1,
+                    end:   self.total_pages,
+                })
+            },
+            None => Ok(()),
+        }
+    }
+}
+
+// TODO ----------
+
+impl Paginator {
+    /// Create `PageItem`s whose pages are chosen by `strategy` instead of the `layout` setting.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn paginate_with<S: LayoutStrategy + ?Sized>(
+        &self,
+        strategy: &S,
+    ) -> Result<Vec<PageItem>, LayoutError> {
+        let mut items = Vec::with_capacity(self.max_item_count);
+
+        self.paginate_with_into(strategy, &mut items)?;
+
+        Ok(items)
+    }
+
+    /// Append `PageItem`s whose pages are chosen by `strategy` to an existing collection. If an error is returned, the items before the invalid one have already been appended.
+    pub fn paginate_with_into<S: LayoutStrategy + ?Sized, E: Extend<PageItem>>(
+        &self,
+        strategy: &S,
+        collection: &mut E,
+    ) -> Result<(), LayoutError> {
+        let (layout, items_counter) = self.control_layout();
+
+        let mut controls = PageItems::new(layout);
+
+        collection.extend(controls.by_ref().take(layout.leading_len()));
+
+        // The strategy lays out the pages which are known to exist, followed by a `PageItem::More` item if `has_more` is set.
+        let known_pages = self.known_pages();
+        let more_count = self.has_more as usize;
+
+        let mut checker = LayoutChecker::new(&known_pages, items_counter - more_count);
+
+        strategy.layout(&known_pages, items_counter - more_count, &mut |item| {
+            if checker.accept(item) {
+                collection.extend(iter::once(item));
+            }
+        });
+
+        let page_item_count = checker.item_count + more_count;
+
+        checker.finish()?;
+
+        if self.has_more {
+            collection.extend(iter::once(PageItem::More {
+                start: non_zero_page!(known_pages.total_pages + 1),
+            }));
+        }
+
+        collection.extend(
+            iter::repeat(PageItem::Placeholder)
+                .take(self.placeholder_count(items_counter, page_item_count)),
+        );
+        collection.extend(controls);
+
+        Ok(())
+    }
+}
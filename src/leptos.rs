@@ -0,0 +1,84 @@
+/*!
+A [Leptos](https://docs.rs/leptos) component which renders a pagination bar.
+
+```rust
+use leptos::prelude::*;
+use paginator::{leptos::PaginationBar, Paginator};
+
+#[component]
+fn Items() -> impl IntoView {
+    let paginator = Paginator::builder(3).current_page(2).build_paginator().unwrap();
+
+    view! { <PaginationBar paginator=paginator on_change=|page: usize| println!("go to page {}", page) /> }
+}
+```
+*/
+
+use ::leptos::{ev::KeyboardEvent, prelude::*};
+
+use crate::{
+    component::{aria_label, click_target, is_button, page_for_key, NAV_ARIA},
+    Paginator,
+};
+
+/// Render the items of `paginator` as buttons, calling `on_change` with the page number when a page is chosen by a click or a key.
+#[component]
+pub fn PaginationBar(
+    /// The paginator to render.
+    paginator: Paginator,
+    /// Called with the page number to change to.
+    #[prop(into)]
+    on_change: Callback<usize>,
+) -> impl IntoView {
+    let items = paginator.paginate();
+
+    let on_keydown = move |event: KeyboardEvent| {
+        if let Some(page) = page_for_key(&paginator, &event.key()) {
+            event.prevent_default();
+            on_change.run(page);
+        }
+    };
+
+    let items = items
+        .into_iter()
+        .map(|item| {
+            let label = item.label().to_string();
+
+            if is_button(&item) {
+                let target = click_target(&item);
+
+                view! {
+                    <li class=item.kind()>
+                        <button
+                            type="button"
+                            aria-label=aria_label(&item)
+                            aria-current=if item.is_current() { Some("page") } else { None }
+                            disabled=item.is_disabled()
+                            on:click=move |_| {
+                                if let Some(page) = target {
+                                    on_change.run(page);
+                                }
+                            }
+                        >
+                            {label}
+                        </button>
+                    </li>
+                }
+                .into_any()
+            } else {
+                view! {
+                    <li class=item.kind()>
+                        <span aria-hidden="true">{label}</span>
+                    </li>
+                }
+                .into_any()
+            }
+        })
+        .collect_view();
+
+    view! {
+        <nav aria-label=NAV_ARIA on:keydown=on_keydown>
+            <ul class="pagination">{items}</ul>
+        </nav>
+    }
+}
@@ -8,6 +8,7 @@ This crate is used for generating pagination bar on webpages or other UIs.
 #### Creating a Pagination Bar Which Has 5 Pages and Is on Page 1
 
 ```rust
+# #[cfg(feature = "alloc")] {
 use paginator::{Paginator, PageItem};
 
 use core::fmt::Write;
@@ -29,7 +30,7 @@ for page_item in paginator.paginate() {
         PageItem::CurrentPage(page) => {
             html.write_fmt(format_args!("<li>{page}</li>", page = page)).unwrap();
         }
-        PageItem::Ignore => {
+        PageItem::Ignore { .. } => {
             html.push_str("<li>...</li>");
         }
         PageItem::Next(page) => {
@@ -42,11 +43,27 @@ for page_item in paginator.paginate() {
         }
     }
 }
+# }
+```
+
+#### Rendering the Pagination Bar as HTML
+
+The `render::html` module can write the same markup without the `match` above.
+
+```rust
+use paginator::{render::html::HtmlRenderer, Paginator};
+
+let paginator = Paginator::builder(5).current_page(1).build_paginator().unwrap();
+
+let mut html = String::new();
+
+HtmlRenderer::new(|page| format!("/page/{}", page)).render(&paginator, &mut html).unwrap();
 ```
 
 #### Creating Pagination Bars Which Has 2 Pages for Different Current Pages
 
 ```rust
+# #[cfg(feature = "alloc")] {
 use paginator::{Paginator, PageItem};
 
 let mut paginator_iter = Paginator::builder(2).build_paginator_iter().unwrap();
@@ -58,6 +75,7 @@ for page_item in paginator_iter.next().unwrap().paginate() {
 for page_item in paginator_iter.next().unwrap().paginate() {
     // current_page == 2
 }
+# }
 ```
 
 ## Pagination Rules
@@ -67,6 +85,7 @@ Before building up a `Paginator`, there is an important option, `max_item_count`
 Look at the following code for more details.
 
 ```rust
+# #[cfg(feature = "alloc")] {
 use paginator::{Paginator, page_items_to_string};
 
 let mut p = Paginator::builder(8).max_item_count(9).start_size(1).end_size(1).build_paginator_iter().unwrap();
@@ -79,6 +98,7 @@ assert_eq!("< 1 ... 4 5* 6 7 8 >", page_items_to_string(p.next().unwrap().pagina
 assert_eq!("< 1 ... 4 5 6* 7 8 >", page_items_to_string(p.next().unwrap().paginate().as_slice()));
 assert_eq!("< 1 ... 4 5 6 7* 8 >", page_items_to_string(p.next().unwrap().paginate().as_slice()));
 assert_eq!("< 1 2 3 4 5 6 7 8*", page_items_to_string(p.next().unwrap().paginate().as_slice()));
+# }
 ```
 
 ## No Std
@@ -90,23 +110,175 @@ Disable the default features to compile this crate without std.
 version = "*"
 default-features = false
 ```
+
+Without the `alloc` feature, `Paginator::paginate` is unavailable. Use `Paginator::page_items` instead, which lazily yields the same `PageItem`s without a global allocator.
+
+```toml
+[dependencies.paginator]
+version = "*"
+default-features = false
+features = ["alloc"]
+```
+
+## Serde Support
+
+Enable the `serde` feature to serialize and deserialize `PageItem` (such as `{"type":"page","page":3}`), `YesNoDepends`, `Layout`, `JumpSteps`, `Paginator` and `PaginatorBuilder`. A `Paginator` is deserialized through `PaginatorBuilder::build_paginator`, so invalid settings are rejected.
+
+```toml
+[dependencies.paginator]
+version = "*"
+features = ["serde"]
+```
+
+## Axum Support
+
+Enable the `axum` feature to extract the `page` and `per_page` query parameters of a request with `paginator::axum::Pagination`. Invalid parameters are rejected with `400 Bad Request` and a JSON body.
+
+```toml
+[dependencies.paginator]
+version = "*"
+features = ["axum"]
+```
+
+## Actix Web Support
+
+Enable the `actix-web` feature to extract the `page` and `per_page` query parameters of a request with `paginator::actix_web::Pagination`, and to add the `Link` and `X-Total-Count` headers of a `Paginator` to a response with `paginator::actix_web::Paginated`.
+
+```toml
+[dependencies.paginator]
+version = "*"
+features = ["actix-web"]
+```
+
+## Template Engine Support
+
+`PageItem` has methods for templates, such as `is_current`, `is_disabled`, `is_ellipsis`, `kind`, `label` and `href`, which can be called from Askama templates directly. For Tera and Handlebars, enable the `tera` feature or the `handlebars` feature to register a `page_items` function (`paginator::tera::PageItemsFunction`) or helper (`paginator::handlebars::PageItemsHelper`), which creates the page items as objects to loop over.
+
+```toml
+[dependencies.paginator]
+version = "*"
+features = ["tera"]
+```
+
+## UI Framework Support
+
+Enable the `leptos`, `yew` or `dioxus` feature for a `PaginationBar` component (in `paginator::leptos`, `paginator::yew` or `paginator::dioxus`), which renders a `Paginator` as buttons with ARIA attributes and keyboard navigation, and calls an `on_change` callback with the chosen page.
+
+```toml
+[dependencies.paginator]
+version = "*"
+features = ["leptos"]
+```
+
+## Ratatui Support
+
+Enable the `ratatui` feature for a `PaginationBar` widget (in `paginator::ratatui`), which renders a `Paginator` in a terminal with styles for the current page, reserved positions and ellipses. It drops items when the area is too narrow, and `PaginationBar::page_at` tells which page was clicked.
+
+```toml
+[dependencies.paginator]
+version = "*"
+features = ["ratatui"]
+```
+
+## Minimum Supported Rust Version
+
+This crate, with the `std` and `alloc` features, supports Rust 1.60 and later. The integration features need the Rust version required by their dependencies, which is newer. With the current releases, they are:
+
+* `yew`: Rust 1.64
+* `serde`: Rust 1.71
+* `axum`: Rust 1.78
+* `dioxus`: Rust 1.83
+* `tera` and `handlebars`: Rust 1.85
+* `actix-web`, `leptos` and `ratatui`: Rust 1.88
 */
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
+macro_rules! non_zero_page {
+    ($page:expr) => {{
+        let page = $page;
+        debug_assert!(page > 0);
+
+        // SAFETY: Every caller passes a page number that is greater than zero.
+        unsafe { core::num::NonZeroUsize::new_unchecked(page) }
+    }};
+}
+
+mod cursor;
+mod jump_steps;
+mod layout;
+mod layout_strategy;
+mod link_header;
 mod page_item;
+mod page_item_array;
+mod page_items;
+mod page_links;
+mod page_query;
+mod page_summary;
+mod page_url;
 mod paginator;
 mod paginator_builder;
 mod paginator_iter;
 mod yes_no_depends;
 
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(any(feature = "tera", feature = "handlebars"))]
+mod template;
+
+pub mod render;
+
+#[cfg(feature = "actix-web")]
+#[cfg_attr(docsrs, doc(cfg(feature = "actix-web")))]
+pub mod actix_web;
+#[cfg(feature = "axum")]
+#[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
+pub mod axum;
+#[cfg(any(feature = "leptos", feature = "yew", feature = "dioxus"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "leptos", feature = "yew", feature = "dioxus"))))]
+pub mod component;
+#[cfg(feature = "dioxus")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dioxus")))]
+// The code generated by `rsx!` uses newer APIs, which are available with the Rust version required by Dioxus.
+#[clippy::msrv = "1.83"]
+pub mod dioxus;
+#[cfg(feature = "handlebars")]
+#[cfg_attr(docsrs, doc(cfg(feature = "handlebars")))]
+pub mod handlebars;
+#[cfg(feature = "leptos")]
+#[cfg_attr(docsrs, doc(cfg(feature = "leptos")))]
+pub mod leptos;
+#[cfg(feature = "ratatui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ratatui")))]
+pub mod ratatui;
+#[cfg(feature = "tera")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tera")))]
+pub mod tera;
+#[cfg(feature = "yew")]
+#[cfg_attr(docsrs, doc(cfg(feature = "yew")))]
+pub mod yew;
+
+#[cfg(feature = "alloc")]
 use alloc::string::String;
+#[cfg(feature = "alloc")]
 use core::fmt::Write;
 
+pub use cursor::*;
+pub use jump_steps::*;
+pub use layout::*;
+pub use layout_strategy::*;
+pub use link_header::*;
 pub use page_item::*;
+pub use page_item_array::*;
+pub use page_items::*;
+pub use page_links::*;
+pub use page_query::*;
+pub use page_summary::*;
+pub use page_url::*;
 pub use paginator_builder::*;
 pub use paginator_iter::*;
 pub use yes_no_depends::*;
@@ -114,6 +286,8 @@ pub use yes_no_depends::*;
 pub use self::paginator::*;
 
 /// Format `PageItem`s to a string. Usually for debug or logging.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub fn page_items_to_string(page_items: &[PageItem]) -> String {
     let mut s = String::new();
 
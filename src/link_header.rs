@@ -0,0 +1,366 @@
+use core::{
+    fmt::{self, Display, Formatter},
+    num::NonZeroUsize,
+};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{PageLinks, Paginator};
+
+/**
+    A URL template whose `{page}` placeholders are replaced with page numbers, such as `https://api.example.com/items?page={page}`.
+
+    ```rust
+    # use paginator::UrlTemplate;
+    let template = UrlTemplate::new("/items?page={page}&sort=name");
+
+    assert_eq!("/items?page=3&sort=name", template.url(3.try_into().unwrap()).to_string());
+    ```
+*/
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct UrlTemplate<'a> {
+    template: &'a str,
+}
+
+impl<'a> UrlTemplate<'a> {
+    /// The placeholder of the page number.
+    pub const PLACEHOLDER: &'static str = "{page}";
+
+    /// Create a `UrlTemplate`.
+    #[inline]
+    pub const fn new(template: &'a str) -> UrlTemplate<'a> {
+        UrlTemplate {
+            template,
+        }
+    }
+
+    /// Create the URL of a page.
+    #[inline]
+    pub const fn url(&self, page: NonZeroUsize) -> TemplateUrl<'a> {
+        TemplateUrl {
+            template: self.template,
+            page,
+        }
+    }
+}
+
+/// The URL of a page made from a `UrlTemplate`. Created by `UrlTemplate::url`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct TemplateUrl<'a> {
+    template: &'a str,
+    page:     NonZeroUsize,
+}
+
+impl TemplateUrl<'_> {
+    /// Return the page number.
+    #[inline]
+    pub const fn page(&self) -> NonZeroUsize {
+        self.page
+    }
+}
+
+impl Display for TemplateUrl<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let mut parts = self.template.split(UrlTemplate::PLACEHOLDER);
+
+        if let Some(part) = parts.next() {
+            f.write_str(part)?;
+
+            for part in parts {
+                f.write_fmt(format_args!("{}{}", self.page, part))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/**
+    The value of an RFC 8288 `Link` header which advertises the `first`, `prev`, `next` and `last` pages. Created by `PageLinks::link_header`.
+
+    ```rust
+    # use paginator::{Paginator, UrlTemplate};
+    let paginator = Paginator::builder(5).current_page(2).build_paginator().unwrap();
+    let template = UrlTemplate::new("https://api.example.com/items?page={page}");
+
+    assert_eq!(
+        r#"<https://api.example.com/items?page=1>; rel="first", <https://api.example.com/items?page=1>; rel="prev", <https://api.example.com/items?page=3>; rel="next", <https://api.example.com/items?page=5>; rel="last""#,
+        paginator.links_with_template(template).link_header().to_string()
+    );
+    ```
+*/
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct LinkHeader<'a, U>(&'a PageLinks<U>);
+
+impl<U: Display> Display for LinkHeader<'_, U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let links = self.0;
+
+        // The `self` link is left out, because it is the URL of the response itself.
+        let entries = [
+            ("first", Some(&links.first)),
+            ("prev", links.prev.as_ref()),
+            ("next", links.next.as_ref()),
+            ("last", links.last.as_ref()),
+        ];
+
+        let mut separator = "";
+
+        for (relation, url) in entries.iter() {
+            if let Some(url) = url {
+                f.write_fmt(format_args!("{}<{}>; rel=\"{}\"", separator, url, relation))?;
+
+                separator = ", ";
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<U: Display> PageLinks<U> {
+    /// Format the links as the value of an RFC 8288 `Link` header.
+    #[inline]
+    pub fn link_header(&self) -> LinkHeader<'_, U> {
+        LinkHeader(self)
+    }
+}
+
+/// The page numbers read from a `Link` header by `parse_link_header`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct LinkHeaderPages {
+    /// The page of the `first` link.
+    pub first:   Option<NonZeroUsize>,
+    /// The page of the `prev` (or `previous`) link.
+    pub prev:    Option<NonZeroUsize>,
+    /// The page of the `self` link.
+    pub current: Option<NonZeroUsize>,
+    /// The page of the `next` link.
+    pub next:    Option<NonZeroUsize>,
+    /// The page of the `last` link.
+    pub last:    Option<NonZeroUsize>,
+}
+
+/// An error returned when a `Link` header cannot be parsed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LinkHeaderError {
+    /// The header does not follow the RFC 8288 syntax at the byte `index`.
+    InvalidSyntax { index: usize },
+    /// The page parameter of the link starting at the byte `index` is not a positive integer.
+    InvalidPage { index: usize },
+}
+
+impl Display for LinkHeaderError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            LinkHeaderError::InvalidSyntax {
+                index,
+            } => f.write_fmt(format_args!("invalid Link header syntax at index {}", index)),
+            LinkHeaderError::InvalidPage {
+                index,
+            } => f.write_fmt(format_args!("invalid page number in the link at index {}", index)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for LinkHeaderError {}
+
+/**
+    Read the page numbers of the `first`, `prev`, `self`, `next` and `last` links from the value of an RFC 8288 `Link` header, such as one returned by a GitHub-style API.
+
+    The page number is the value of the `page_param` query parameter of each URL. A URL without that parameter is regarded as page 1. Links with other relation types are ignored.
+
+    ```rust
+    # use paginator::parse_link_header;
+    let pages = parse_link_header(
+        r#"<https://api.example.com/items?page=3>; rel="next", <https://api.example.com/items?page=50>; rel="last""#,
+        "page",
+    )
+    .unwrap();
+
+    assert_eq!(Some(3), pages.next.map(|page| page.get()));
+    assert_eq!(Some(50), pages.last.map(|page| page.get()));
+    assert_eq!(None, pages.prev);
+    ```
+*/
+pub fn parse_link_header(
+    value: &str,
+    page_param: &str,
+) -> Result<LinkHeaderPages, LinkHeaderError> {
+    let bytes = value.as_bytes();
+    let mut pages = LinkHeaderPages::default();
+    let mut index = 0;
+
+    loop {
+        // Empty list elements are allowed.
+        while index < bytes.len() && matches!(bytes[index], b' ' | b'\t' | b',') {
+            index += 1;
+        }
+
+        if index == bytes.len() {
+            break;
+        }
+
+        // link-value = "<" URI-Reference ">" *( OWS ";" OWS link-param )
+        let link_index = index;
+
+        if bytes[index] != b'<' {
+            return Err(LinkHeaderError::InvalidSyntax {
+                index,
+            });
+        }
+
+        let uri_end = match value[index..].find('>') {
+            Some(offset) => index + offset,
+            None => {
+                return Err(LinkHeaderError::InvalidSyntax {
+                    index,
+                })
+            },
+        };
+
+        let uri = &value[index + 1..uri_end];
+
+        index = skip_whitespace(bytes, uri_end + 1);
+
+        let mut relations = "";
+
+        while index < bytes.len() && bytes[index] == b';' {
+            index = skip_whitespace(bytes, index + 1);
+
+            let name_end = index + bytes[index..].iter().take_while(|&&b| is_token_byte(b)).count();
+
+            if name_end == index {
+                return Err(LinkHeaderError::InvalidSyntax {
+                    index,
+                });
+            }
+
+            let name = &value[index..name_end];
+
+            index = skip_whitespace(bytes, name_end);
+
+            let mut param_value = "";
+
+            if index < bytes.len() && bytes[index] == b'=' {
+                index = skip_whitespace(bytes, index + 1);
+
+                if index < bytes.len() && bytes[index] == b'"' {
+                    let start = index + 1;
+
+                    index = start;
+
+                    // Quoted strings can contain commas and semicolons, and escape characters with a backslash.
+                    loop {
+                        match bytes.get(index) {
+                            Some(b'"') => break,
+                            Some(b'\\') => index += 2,
+                            Some(_) => index += 1,
+                            None => {
+                                return Err(LinkHeaderError::InvalidSyntax {
+                                    index: start - 1
+                                })
+                            },
+                        }
+                    }
+
+                    param_value = &value[start..index];
+
+                    index += 1;
+                } else {
+                    let start = index;
+
+                    index += bytes[index..].iter().take_while(|&&b| is_token_byte(b)).count();
+
+                    param_value = &value[start..index];
+                }
+
+                index = skip_whitespace(bytes, index);
+            }
+
+            // Only the first rel parameter is used.
+            if relations.is_empty() && name.eq_ignore_ascii_case("rel") {
+                relations = param_value;
+            }
+        }
+
+        match bytes.get(index) {
+            None => (),
+            Some(b',') => index += 1,
+            Some(_) => {
+                return Err(LinkHeaderError::InvalidSyntax {
+                    index,
+                })
+            },
+        }
+
+        for relation in relations.split_ascii_whitespace() {
+            let slot = if relation.eq_ignore_ascii_case("first") {
+                &mut pages.first
+            } else if relation.eq_ignore_ascii_case("prev")
+                || relation.eq_ignore_ascii_case("previous")
+            {
+                &mut pages.prev
+            } else if relation.eq_ignore_ascii_case("self") {
+                &mut pages.current
+            } else if relation.eq_ignore_ascii_case("next") {
+                &mut pages.next
+            } else if relation.eq_ignore_ascii_case("last") {
+                &mut pages.last
+            } else {
+                continue;
+            };
+
+            let page = page_of_uri(uri, page_param).ok_or(LinkHeaderError::InvalidPage {
+                index: link_index,
+            })?;
+
+            *slot = Some(page);
+        }
+    }
+
+    Ok(pages)
+}
+
+/// Read the page number from the query string of a URI.
+fn page_of_uri(uri: &str, page_param: &str) -> Option<NonZeroUsize> {
+    let uri = uri.split('#').next().unwrap_or("");
+
+    let query = match uri.find('?') {
+        Some(index) => &uri[index + 1..],
+        None => "",
+    };
+
+    for pair in query.split('&') {
+        let mut pair = pair.splitn(2, '=');
+
+        if pair.next() == Some(page_param) {
+            return pair.next().unwrap_or("").parse().ok();
+        }
+    }
+
+    Some(non_zero_page!(1))
+}
+
+#[inline]
+fn skip_whitespace(bytes: &[u8], index: usize) -> usize {
+    index + bytes[index..].iter().take_while(|&&b| b == b' ' || b == b'\t').count()
+}
+
+/// Whether the byte can be a part of an RFC 7230 token.
+#[inline]
+fn is_token_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b)
+}
+
+// TODO ----------
+
+impl Paginator {
+    /// Create the links of the pages around the current page, with the URLs made from `template`.
+    #[inline]
+    pub fn links_with_template<'a>(&self, template: UrlTemplate<'a>) -> PageLinks<TemplateUrl<'a>> {
+        self.links(|page| template.url(page))
+    }
+}
@@ -1,17 +1,30 @@
 use core::{
     fmt::{self, Display, Formatter},
     num::NonZeroUsize,
+    ops::RangeInclusive,
 };
 
+use crate::{PageHref, PageUrl};
+
 /// An item displayed in a pagination bar.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "crate::serde_impls::PageItemRepr",
+        into = "crate::serde_impls::PageItemRepr"
+    )
+)]
 pub enum PageItem {
     /// A link to a regular page.
     Page(NonZeroUsize),
     /// The current page without a regular link.
     CurrentPage(NonZeroUsize),
-    /// A marker for a hidden range of pages.
-    Ignore,
+    /// A marker for a hidden range of pages, from `start` to `end` (inclusive).
+    Ignore { start: NonZeroUsize, end: NonZeroUsize },
+    /// A marker for the pages from `start` onwards, when the number of pages is unknown.
+    More { start: NonZeroUsize },
     /// A link to the previous page.
     Prev(NonZeroUsize),
     /// A link to the next page.
@@ -20,6 +33,179 @@ pub enum PageItem {
     ReservedPrev,
     /// A reserved next-page position without a valid target.
     ReservedNext,
+    /// A link to the first page.
+    First(NonZeroUsize),
+    /// A link to the last page.
+    Last(NonZeroUsize),
+    /// A reserved first-page position without a valid target.
+    ReservedFirst,
+    /// A reserved last-page position without a valid target.
+    ReservedLast,
+    /// An empty position which keeps the width of a pagination bar in the fixed-width mode.
+    Placeholder,
+    /// A link to the page `step` pages before the current page, clamped to the first page.
+    JumpBackward { page: NonZeroUsize, step: usize },
+    /// A link to the page `step` pages after the current page, clamped to the last page.
+    JumpForward { page: NonZeroUsize, step: usize },
+    /// A reserved backward-jump position without a valid target, which is on the first page.
+    ReservedJumpBackward { step: usize },
+    /// A reserved forward-jump position without a valid target, which is on the last page.
+    ReservedJumpForward { step: usize },
+}
+
+impl PageItem {
+    /// Return the page this item links to, or the page number of a `PageItem::CurrentPage` item. Ellipses, placeholders and reserved control positions return `None`.
+    #[inline]
+    pub fn page(&self) -> Option<NonZeroUsize> {
+        match self {
+            PageItem::Page(page)
+            | PageItem::CurrentPage(page)
+            | PageItem::Prev(page)
+            | PageItem::Next(page)
+            | PageItem::First(page)
+            | PageItem::Last(page)
+            | PageItem::JumpBackward {
+                page, ..
+            }
+            | PageItem::JumpForward {
+                page, ..
+            } => Some(*page),
+            _ => None,
+        }
+    }
+
+    /// Return the pages hidden by a `PageItem::Ignore` item.
+    #[inline]
+    pub fn hidden_pages(&self) -> Option<RangeInclusive<usize>> {
+        match self {
+            PageItem::Ignore {
+                start,
+                end,
+            } => Some(start.get()..=end.get()),
+            _ => None,
+        }
+    }
+
+    /**
+        Return a representative page to jump to from a `PageItem::Ignore` item, which is the middle of the hidden pages. An item whose `start` is greater than its `end` has no hidden pages, so it returns `None`.
+
+        ```rust
+        # use paginator::Paginator;
+        let paginator = Paginator::builder(50).current_page(3).build_paginator().unwrap();
+        let ignore = paginator.page_items().find(|item| item.hidden_pages().is_some()).unwrap();
+
+        assert_eq!(Some(6..=49), ignore.hidden_pages());
+        assert_eq!(27, ignore.jump_target().unwrap().get());
+        ```
+    */
+    #[inline]
+    pub fn jump_target(&self) -> Option<NonZeroUsize> {
+        match self {
+            PageItem::Ignore {
+                start,
+                end,
+            } if start <= end => Some(non_zero_page!(start.get() + (end.get() - start.get()) / 2)),
+            _ => None,
+        }
+    }
+
+    /// Whether this item is the current page.
+    #[inline]
+    pub const fn is_current(&self) -> bool {
+        matches!(self, PageItem::CurrentPage(_))
+    }
+
+    /// Whether this item is a reserved control position or a placeholder, which should be rendered without a link.
+    #[inline]
+    pub const fn is_disabled(&self) -> bool {
+        matches!(
+            self,
+            PageItem::ReservedPrev
+                | PageItem::ReservedNext
+                | PageItem::ReservedFirst
+                | PageItem::ReservedLast
+                | PageItem::Placeholder
+                | PageItem::ReservedJumpBackward { .. }
+                | PageItem::ReservedJumpForward { .. }
+        )
+    }
+
+    /// Whether this item is an ellipsis, which is `PageItem::Ignore` or `PageItem::More`.
+    #[inline]
+    pub const fn is_ellipsis(&self) -> bool {
+        matches!(self, PageItem::Ignore { .. } | PageItem::More { .. })
+    }
+
+    /// Return the name of the variant in snake case, such as `current_page` or `reserved_prev`, which is the same as the `type` field of the serialized form. Useful as a CSS class.
+    #[inline]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            PageItem::Page(_) => "page",
+            PageItem::CurrentPage(_) => "current_page",
+            PageItem::Ignore {
+                ..
+            } => "ignore",
+            PageItem::More {
+                ..
+            } => "more",
+            PageItem::Prev(_) => "prev",
+            PageItem::Next(_) => "next",
+            PageItem::ReservedPrev => "reserved_prev",
+            PageItem::ReservedNext => "reserved_next",
+            PageItem::First(_) => "first",
+            PageItem::Last(_) => "last",
+            PageItem::ReservedFirst => "reserved_first",
+            PageItem::ReservedLast => "reserved_last",
+            PageItem::Placeholder => "placeholder",
+            PageItem::JumpBackward {
+                ..
+            } => "jump_backward",
+            PageItem::JumpForward {
+                ..
+            } => "jump_forward",
+            PageItem::ReservedJumpBackward {
+                ..
+            } => "reserved_jump_backward",
+            PageItem::ReservedJumpForward {
+                ..
+            } => "reserved_jump_forward",
+        }
+    }
+
+    /**
+        Return the text to display for this item, which is the page number of a page, `…` for an ellipsis, and arrows for controls. Unlike `Display`, which is for debugging, this is for users.
+
+        ```rust
+        # use paginator::{Paginator, PageUrl};
+        let page_url = PageUrl::new("/items");
+
+        let html = Paginator::builder(20)
+            .current_page(10)
+            .build_paginator()
+            .unwrap()
+            .page_items()
+            .map(|item| match item.href(&page_url) {
+                Some(href) if !item.is_current() => format!("<a href=\"{}\">{}</a>", href, item.label()),
+                _ => format!("<span>{}</span>", item.label()),
+            })
+            .collect::<String>();
+
+        assert_eq!(
+            "<a href=\"/items?page=9\">«</a><a href=\"/items?page=1\">1</a><span>…</span><a href=\"/items?page=9\">9</a><span>10</span><a href=\"/items?page=11\">11</a><span>…</span><a href=\"/items?page=20\">20</a><a href=\"/items?page=11\">»</a>",
+            html
+        );
+        ```
+    */
+    #[inline]
+    pub const fn label(&self) -> PageItemLabel {
+        PageItemLabel(*self)
+    }
+
+    /// Return the URL this item links to, made by `page_url`. See `PageItem::page`.
+    #[inline]
+    pub fn href<'a>(&self, page_url: &PageUrl<'a>) -> Option<PageHref<'a>> {
+        page_url.item_url(self)
+    }
 }
 
 impl Display for PageItem {
@@ -28,11 +214,70 @@ impl Display for PageItem {
         match self {
             PageItem::Page(page) => f.write_fmt(format_args!("{}", page)),
             PageItem::CurrentPage(page) => f.write_fmt(format_args!("{}*", page)),
-            PageItem::Ignore => f.write_str("..."),
+            PageItem::Ignore {
+                ..
+            } => f.write_str("..."),
+            PageItem::More {
+                ..
+            } => f.write_str("..."),
             PageItem::Next(_) => f.write_str(">"),
             PageItem::Prev(_) => f.write_str("<"),
             PageItem::ReservedPrev => f.write_str(")"),
             PageItem::ReservedNext => f.write_str("("),
+            PageItem::First(_) => f.write_str("<<"),
+            PageItem::Last(_) => f.write_str(">>"),
+            PageItem::ReservedFirst => f.write_str("))"),
+            PageItem::ReservedLast => f.write_str("(("),
+            PageItem::Placeholder => f.write_str("_"),
+            PageItem::JumpBackward {
+                step, ..
+            } => f.write_fmt(format_args!("-{}", step)),
+            PageItem::JumpForward {
+                step, ..
+            } => f.write_fmt(format_args!("+{}", step)),
+            PageItem::ReservedJumpBackward {
+                step,
+            } => f.write_fmt(format_args!("(-{})", step)),
+            PageItem::ReservedJumpForward {
+                step,
+            } => f.write_fmt(format_args!("(+{})", step)),
+        }
+    }
+}
+
+/// The text to display for a `PageItem`. Created by `PageItem::label`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PageItemLabel(PageItem);
+
+impl Display for PageItemLabel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self.0 {
+            PageItem::Page(page) | PageItem::CurrentPage(page) => {
+                f.write_fmt(format_args!("{}", page))
+            },
+            PageItem::Ignore {
+                ..
+            }
+            | PageItem::More {
+                ..
+            } => f.write_str("…"),
+            PageItem::Prev(_) | PageItem::ReservedPrev => f.write_str("«"),
+            PageItem::Next(_) | PageItem::ReservedNext => f.write_str("»"),
+            PageItem::First(_) | PageItem::ReservedFirst => f.write_str("« First"),
+            PageItem::Last(_) | PageItem::ReservedLast => f.write_str("Last »"),
+            PageItem::Placeholder => Ok(()),
+            PageItem::JumpBackward {
+                step, ..
+            }
+            | PageItem::ReservedJumpBackward {
+                step,
+            } => f.write_fmt(format_args!("−{}", step)),
+            PageItem::JumpForward {
+                step, ..
+            }
+            | PageItem::ReservedJumpForward {
+                step,
+            } => f.write_fmt(format_args!("+{}", step)),
         }
     }
 }
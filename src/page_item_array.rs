@@ -0,0 +1,101 @@
+use core::{
+    fmt::{self, Debug, Formatter},
+    ops::Deref,
+    slice::Iter,
+};
+
+use crate::{PageItem, Paginator};
+
+/// A fixed-capacity container of `PageItem`s stored on the stack. Created by `Paginator::paginate_array`.
+#[derive(Copy, Clone)]
+pub struct PageItemArray<const N: usize> {
+    items: [PageItem; N],
+    len:   usize,
+}
+
+impl<const N: usize> PageItemArray<N> {
+    /// Return the number of `PageItem`s which can be stored.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Extract a slice containing the stored `PageItem`s.
+    #[inline]
+    pub fn as_slice(&self) -> &[PageItem] {
+        &self.items[..self.len]
+    }
+}
+
+impl<const N: usize> Deref for PageItemArray<N> {
+    type Target = [PageItem];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> Debug for PageItemArray<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<const N: usize> PartialEq for PageItemArray<N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<const N: usize> Eq for PageItemArray<N> {}
+
+impl<'a, const N: usize> IntoIterator for &'a PageItemArray<N> {
+    type IntoIter = Iter<'a, PageItem>;
+    type Item = &'a PageItem;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+// TODO ----------
+
+impl Paginator {
+    /**
+        Create `PageItem`s in a `PageItemArray` without allocating.
+
+        Return `None` if `N` is smaller than `max_item_count`, so the check does not depend on the current page.
+
+        ```rust
+        # #[cfg(feature = "alloc")] {
+        # use paginator::{page_items_to_string, Paginator};
+        let paginator = Paginator::builder(20).current_page(10).build_paginator().unwrap();
+
+        let items = paginator.paginate_array::<9>().unwrap();
+
+        assert_eq!("< 1 ... 9 10* 11 ... 20 >", page_items_to_string(&items));
+        assert!(paginator.paginate_array::<8>().is_none());
+        # }
+        ```
+    */
+    pub fn paginate_array<const N: usize>(&self) -> Option<PageItemArray<N>> {
+        if N < self.max_item_count {
+            return None;
+        }
+
+        let mut array = PageItemArray {
+            items: [PageItem::ReservedPrev; N], len: 0
+        };
+
+        for item in self.page_items() {
+            array.items[array.len] = item;
+            array.len += 1;
+        }
+
+        Some(array)
+    }
+}
@@ -0,0 +1,497 @@
+use core::iter::FusedIterator;
+
+use crate::{JumpSteps, PageItem};
+
+/// A run of consecutive pages.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct PageRun {
+    first: usize,
+    len:   usize,
+}
+
+/// Pages at growing distances (1, 2, 5, 10, 20, 50, ...) from the current page, as used by `Layout::Logarithmic`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct LogarithmicPages {
+    /// The number of distances shown before the current page.
+    left:  usize,
+    /// The number of distances shown after the current page.
+    right: usize,
+}
+
+/// Return the distance at `index` in the sequence 1, 2, 5, 10, 20, 50, ..., or `None` if it overflows.
+#[inline]
+fn logarithmic_distance(index: usize) -> Option<usize> {
+    const MANTISSAS: [usize; 3] = [1, 2, 5];
+
+    10usize.checked_pow((index / 3) as u32)?.checked_mul(MANTISSAS[index % 3])
+}
+
+/// The shape of a pagination bar: `[first] [prev] [jumps] run (ignore) run (ignore) run [jumps] [next] [last]`, or `[first] [prev] [jumps] logarithmic pages [jumps] [next] [last]`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct ItemLayout {
+    total_pages:             usize,
+    current_page:            usize,
+    pub(crate) first:        Option<PageItem>,
+    pub(crate) prev:         Option<PageItem>,
+    pub(crate) jump_steps:   JumpSteps,
+    runs:                    [PageRun; 3],
+    ignores:                 [bool; 2],
+    /// The index of the run which receives pushed pages.
+    run_index:               usize,
+    /// Replaces the runs if set.
+    logarithmic:             Option<LogarithmicPages>,
+    /// The first page of a `PageItem::More` item after the pages.
+    more:                    Option<usize>,
+    /// The number of `PageItem::Placeholder` items after the pages.
+    pub(crate) placeholders: usize,
+    pub(crate) next:         Option<PageItem>,
+    pub(crate) last:         Option<PageItem>,
+}
+
+impl ItemLayout {
+    #[inline]
+    pub(crate) const fn new(total_pages: usize, current_page: usize) -> ItemLayout {
+        const EMPTY: PageRun = PageRun {
+            first: 0, len: 0
+        };
+
+        ItemLayout {
+            total_pages,
+            current_page,
+            first: None,
+            prev: None,
+            jump_steps: JumpSteps::new(),
+            runs: [EMPTY; 3],
+            ignores: [false; 2],
+            run_index: 0,
+            logarithmic: None,
+            more: None,
+            placeholders: 0,
+            next: None,
+            last: None,
+        }
+    }
+
+    /// Append the pages from `first` to `last` (inclusive). Nothing is appended if `first > last`.
+    pub(crate) fn push_pages(&mut self, first: usize, last: usize) {
+        if first > last {
+            return;
+        }
+
+        let run = &mut self.runs[self.run_index];
+
+        if run.len == 0 {
+            run.first = first;
+        } else {
+            debug_assert_eq!(run.first + run.len, first);
+        }
+
+        run.len += last - first + 1;
+    }
+
+    /// Append a marker for a hidden range of pages.
+    #[inline]
+    pub(crate) fn push_ignore(&mut self) {
+        self.ignores[self.run_index] = true;
+        self.run_index += 1;
+    }
+
+    /// Append a marker for the pages after `last_page` whose number is unknown. A trailing ignore marker is replaced because it would hide pages next to them.
+    pub(crate) fn push_more(&mut self, last_page: usize) {
+        let start = if self.logarithmic.is_none()
+            && self.run_index > 0
+            && self.runs[self.run_index].len == 0
+        {
+            self.run_index -= 1;
+            self.ignores[self.run_index] = false;
+
+            let run = &self.runs[self.run_index];
+
+            if run.len > 0 {
+                run.first + run.len
+            } else {
+                1
+            }
+        } else {
+            last_page + 1
+        };
+
+        self.more = Some(start);
+    }
+
+    /// Show pages at growing distances from the current page instead of the runs, with as many distances as `items_counter` items can hold.
+    pub(crate) fn push_logarithmic(&mut self, items_counter: usize) {
+        let max_distances = |limit: usize| {
+            (0..)
+                .take_while(|&index| matches!(logarithmic_distance(index), Some(d) if d < limit))
+                .count()
+        };
+
+        let max_left = max_distances(self.current_page - 1);
+        let max_right = max_distances(self.total_pages - self.current_page);
+
+        let mut pages = LogarithmicPages {
+            left: 0, right: 0
+        };
+
+        // Grow both sides alternately, so the budget is shared evenly unless one side runs out of pages.
+        loop {
+            let mut grown = false;
+
+            if pages.left < max_left {
+                let candidate = LogarithmicPages {
+                    left: pages.left + 1,
+                    ..pages
+                };
+
+                if self.logarithmic_items(candidate).count() <= items_counter {
+                    pages = candidate;
+                    grown = true;
+                }
+            }
+
+            if pages.right < max_right {
+                let candidate = LogarithmicPages {
+                    right: pages.right + 1,
+                    ..pages
+                };
+
+                if self.logarithmic_items(candidate).count() <= items_counter {
+                    pages = candidate;
+                    grown = true;
+                }
+            }
+
+            if !grown {
+                break;
+            }
+        }
+
+        self.logarithmic = Some(pages);
+    }
+
+    /// Get the page at `index` among the pages shown by `pages`, in ascending order.
+    fn logarithmic_page(&self, pages: LogarithmicPages, mut index: usize) -> usize {
+        if self.current_page > 1 {
+            if index == 0 {
+                return 1;
+            }
+
+            index -= 1;
+        }
+
+        if index < pages.left {
+            return self.current_page - logarithmic_distance(pages.left - 1 - index).unwrap();
+        }
+
+        index -= pages.left;
+
+        if index == 0 {
+            return self.current_page;
+        }
+
+        index -= 1;
+
+        if index < pages.right {
+            return self.current_page + logarithmic_distance(index).unwrap();
+        }
+
+        debug_assert_eq!(0, index - pages.right);
+
+        self.total_pages
+    }
+
+    /// Iterate over the page section shown by `pages`, with an ignore marker for every gap.
+    fn logarithmic_items(&self, pages: LogarithmicPages) -> impl Iterator<Item = PageItem> + '_ {
+        let page_count = (self.current_page > 1) as usize
+            + pages.left
+            + 1
+            + pages.right
+            + (self.current_page < self.total_pages) as usize;
+
+        (0..page_count).flat_map(move |index| {
+            let page = self.logarithmic_page(pages, index);
+
+            let ignore = if index > 0 {
+                let previous_page = self.logarithmic_page(pages, index - 1);
+
+                if page - previous_page > 1 {
+                    Some(PageItem::Ignore {
+                        start: non_zero_page!(previous_page + 1),
+                        end:   non_zero_page!(page - 1),
+                    })
+                } else {
+                    None
+                }
+            } else {
+                None
+            };
+
+            let item = if page == self.current_page {
+                PageItem::CurrentPage(non_zero_page!(page))
+            } else {
+                PageItem::Page(non_zero_page!(page))
+            };
+
+            ignore.into_iter().chain(Some(item))
+        })
+    }
+
+    /// The number of items of the pages, without placeholders.
+    pub(crate) fn pages_len(&self) -> usize {
+        self.more.is_some() as usize
+            + match self.logarithmic {
+                Some(pages) => self.logarithmic_items(pages).count(),
+                None => {
+                    self.runs[0].len
+                        + self.ignores[0] as usize
+                        + self.runs[1].len
+                        + self.ignores[1] as usize
+                        + self.runs[2].len
+                },
+            }
+    }
+
+    /// The number of items before the pages.
+    #[inline]
+    pub(crate) fn leading_len(&self) -> usize {
+        self.first.is_some() as usize + self.prev.is_some() as usize + self.jump_steps.len()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.first.is_some() as usize
+            + self.prev.is_some() as usize
+            + self.jump_steps.len() * 2
+            + self.pages_len()
+            + self.placeholders
+            + self.next.is_some() as usize
+            + self.last.is_some() as usize
+    }
+
+    /// Get the `PageItem::Ignore` item after the run at `run_index`.
+    fn ignore_at(&self, run_index: usize) -> PageItem {
+        let before = &self.runs[run_index];
+        let after = &self.runs[run_index + 1];
+
+        // Only the first run can be empty before an ignore marker, and only the last non-empty run can be followed by an empty one.
+        let start = if before.len > 0 { before.first + before.len } else { 1 };
+        let end = if after.len > 0 { after.first - 1 } else { self.total_pages };
+
+        debug_assert!(start <= end);
+
+        PageItem::Ignore {
+            start: non_zero_page!(start), end: non_zero_page!(end)
+        }
+    }
+
+    /// Get the item at `index`, which must be smaller than `len()`.
+    pub(crate) fn item_at(&self, mut index: usize) -> PageItem {
+        for item in [self.first, self.prev].iter().flatten() {
+            if index == 0 {
+                return *item;
+            }
+
+            index -= 1;
+        }
+
+        let jump_count = self.jump_steps.len();
+
+        if index < jump_count {
+            // The largest backward jump comes first.
+            let step = self.jump_steps[jump_count - 1 - index];
+
+            return if self.current_page == 1 {
+                PageItem::ReservedJumpBackward {
+                    step,
+                }
+            } else {
+                PageItem::JumpBackward {
+                    page: non_zero_page!(self.current_page.saturating_sub(step).max(1)),
+                    step,
+                }
+            };
+        }
+
+        index -= jump_count;
+
+        if let Some(pages) = self.logarithmic {
+            for item in self.logarithmic_items(pages) {
+                if index == 0 {
+                    return item;
+                }
+
+                index -= 1;
+            }
+        }
+
+        for (i, run) in self.runs.iter().enumerate() {
+            if index < run.len {
+                let page = run.first + index;
+
+                return if page == self.current_page {
+                    PageItem::CurrentPage(non_zero_page!(page))
+                } else {
+                    PageItem::Page(non_zero_page!(page))
+                };
+            }
+
+            index -= run.len;
+
+            if i < 2 && self.ignores[i] {
+                if index == 0 {
+                    return self.ignore_at(i);
+                }
+
+                index -= 1;
+            }
+        }
+
+        if let Some(start) = self.more {
+            if index == 0 {
+                return PageItem::More {
+                    start: non_zero_page!(start)
+                };
+            }
+
+            index -= 1;
+        }
+
+        if index < self.placeholders {
+            return PageItem::Placeholder;
+        }
+
+        index -= self.placeholders;
+
+        if index < jump_count {
+            let step = self.jump_steps[index];
+
+            return if self.current_page == self.total_pages {
+                PageItem::ReservedJumpForward {
+                    step,
+                }
+            } else {
+                PageItem::JumpForward {
+                    page: non_zero_page!(self
+                        .current_page
+                        .saturating_add(step)
+                        .min(self.total_pages)),
+                    step,
+                }
+            };
+        }
+
+        index -= jump_count;
+
+        match (self.next, index) {
+            (Some(next), 0) => next,
+            (Some(_), _) => self.last.unwrap(),
+            (None, _) => {
+                debug_assert_eq!(0, index);
+
+                self.last.unwrap()
+            },
+        }
+    }
+}
+
+/// An iterator which lazily yields the `PageItem`s of a `Paginator`. Created by `Paginator::page_items`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PageItems {
+    layout: ItemLayout,
+    front:  usize,
+    back:   usize,
+}
+
+impl PageItems {
+    #[inline]
+    pub(crate) fn new(layout: ItemLayout) -> PageItems {
+        let back = layout.len();
+
+        PageItems {
+            layout,
+            front: 0,
+            back,
+        }
+    }
+}
+
+impl Iterator for PageItems {
+    type Item = PageItem;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let item = self.layout.item_at(self.front);
+
+            self.front += 1;
+
+            Some(item)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize
+    where
+        Self: Sized, {
+        self.back - self.front
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item>
+    where
+        Self: Sized, {
+        self.next_back()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n < self.back - self.front {
+            self.front += n;
+            self.next()
+        } else {
+            self.front = self.back;
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for PageItems {
+    #[inline]
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+impl FusedIterator for PageItems {}
+
+impl DoubleEndedIterator for PageItems {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+
+            Some(self.layout.item_at(self.back))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n < self.back - self.front {
+            self.back -= n;
+            self.next_back()
+        } else {
+            self.back = self.front;
+            None
+        }
+    }
+}
@@ -0,0 +1,336 @@
+use core::{
+    fmt::{self, Display, Formatter, Write},
+    num::NonZeroUsize,
+};
+
+use crate::{PageHref, PageUrl, Paginator};
+
+/**
+    The URLs of the pages around the current page, as used by the `links` object of JSON:API responses and the `_links` object of HAL responses.
+
+    ```rust
+    # use paginator::Paginator;
+    let paginator = Paginator::builder(5).current_page(2).build_paginator().unwrap();
+    let links = paginator.links_with_base_url("/articles?sort=new", "page");
+
+    assert_eq!(
+        r#"{"first":"/articles?sort=new&page=1","prev":"/articles?sort=new&page=1","self":"/articles?sort=new&page=2","next":"/articles?sort=new&page=3","last":"/articles?sort=new&page=5"}"#,
+        links.json_api().to_string()
+    );
+    assert_eq!(
+        r#"{"first":{"href":"/articles?sort=new&page=1"},"prev":{"href":"/articles?sort=new&page=1"},"self":{"href":"/articles?sort=new&page=2"},"next":{"href":"/articles?sort=new&page=3"},"last":{"href":"/articles?sort=new&page=5"}}"#,
+        links.hal().to_string()
+    );
+    ```
+*/
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PageLinks<U> {
+    /// The URL of the first page.
+    pub first:   U,
+    /// The URL of the previous page, if any.
+    pub prev:    Option<U>,
+    /// The URL of the current page, which is the `self` link.
+    pub current: U,
+    /// The URL of the next page, if any.
+    pub next:    Option<U>,
+    /// The URL of the last page, unless the number of pages is unknown.
+    pub last:    Option<U>,
+}
+
+impl<U> PageLinks<U> {
+    /// Iterate over the links which exist, as `(relation, url)` pairs in the order `first`, `prev`, `self`, `next` and `last`.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &U)> {
+        [
+            ("first", Some(&self.first)),
+            ("prev", self.prev.as_ref()),
+            ("self", Some(&self.current)),
+            ("next", self.next.as_ref()),
+            ("last", self.last.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(relation, url)| url.map(|url| (relation, url)))
+    }
+}
+
+impl<U: Display> PageLinks<U> {
+    /// Format the links as a JSON:API `links` object. Missing links are `null`.
+    #[inline]
+    pub fn json_api(&self) -> JsonApiLinks<'_, U> {
+        JsonApiLinks(self)
+    }
+
+    /// Format the links as a HAL `_links` object. Missing links are omitted.
+    #[inline]
+    pub fn hal(&self) -> HalLinks<'_, U> {
+        HalLinks(self)
+    }
+}
+
+/// The JSON:API `links` object of `PageLinks`. Created by `PageLinks::json_api`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct JsonApiLinks<'a, U>(&'a PageLinks<U>);
+
+impl<U: Display> Display for JsonApiLinks<'_, U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let links = self.0;
+
+        let entries = [
+            ("first", Some(&links.first)),
+            ("prev", links.prev.as_ref()),
+            ("self", Some(&links.current)),
+            ("next", links.next.as_ref()),
+            ("last", links.last.as_ref()),
+        ];
+
+        f.write_char('{')?;
+
+        for (i, (relation, url)) in entries.iter().enumerate() {
+            if i > 0 {
+                f.write_char(',')?;
+            }
+
+            f.write_fmt(format_args!("\"{}\":", relation))?;
+
+            match url {
+                Some(url) => write_json_string(f, url)?,
+                None => f.write_str("null")?,
+            }
+        }
+
+        f.write_char('}')
+    }
+}
+
+/// The HAL `_links` object of `PageLinks`. Created by `PageLinks::hal`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct HalLinks<'a, U>(&'a PageLinks<U>);
+
+impl<U: Display> Display for HalLinks<'_, U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_char('{')?;
+
+        for (i, (relation, url)) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_char(',')?;
+            }
+
+            f.write_fmt(format_args!("\"{}\":{{\"href\":", relation))?;
+            write_json_string(f, url)?;
+            f.write_char('}')?;
+        }
+
+        f.write_char('}')
+    }
+}
+
+/**
+    The pagination numbers of a `Paginator`, as used by the `meta` object of JSON:API responses and the top-level properties of HAL responses.
+
+    ```rust
+    # use paginator::PaginatorBuilder;
+    let paginator = PaginatorBuilder::from_items(95, 10).current_page(3).build_paginator().unwrap();
+
+    assert_eq!(
+        r#"{"current_page":3,"total_pages":10,"per_page":10,"total_items":95}"#,
+        paginator.meta().to_string()
+    );
+    ```
+*/
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PageMeta {
+    pub(crate) current_page: usize,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) total_pages:  Option<usize>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) per_page:     Option<usize>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub(crate) total_items:  Option<usize>,
+}
+
+impl PageMeta {
+    /// Return the current page number.
+    #[inline]
+    pub const fn current_page(&self) -> usize {
+        self.current_page
+    }
+
+    /// Return the total number of pages, unless it is unknown.
+    #[inline]
+    pub const fn total_pages(&self) -> Option<usize> {
+        self.total_pages
+    }
+
+    /// Return the number of items on each page, if known.
+    #[inline]
+    pub const fn per_page(&self) -> Option<usize> {
+        self.per_page
+    }
+
+    /// Return the number of items, if known.
+    #[inline]
+    pub const fn total_items(&self) -> Option<usize> {
+        self.total_items
+    }
+}
+
+impl Display for PageMeta {
+    /// Format the numbers as a JSON object. Unknown numbers are omitted.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_fmt(format_args!("{{\"current_page\":{}", self.current_page))?;
+
+        let entries = [
+            ("total_pages", self.total_pages),
+            ("per_page", self.per_page),
+            ("total_items", self.total_items),
+        ];
+
+        for (name, value) in entries.iter() {
+            if let Some(value) = value {
+                f.write_fmt(format_args!(",\"{}\":{}", name, value))?;
+            }
+        }
+
+        f.write_char('}')
+    }
+}
+
+/// Write a value as a JSON string literal.
+pub(crate) fn write_json_string<W: Write>(
+    out: &mut W,
+    value: impl Display,
+) -> Result<(), fmt::Error> {
+    out.write_char('"')?;
+    JsonEscape(out).write_fmt(format_args!("{}", value))?;
+    out.write_char('"')
+}
+
+/// A writer which escapes JSON string special characters before passing them to the inner writer.
+struct JsonEscape<'w, W>(&'w mut W);
+
+impl<W: Write> Write for JsonEscape<'_, W> {
+    fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
+        let mut last = 0;
+
+        for (i, b) in s.bytes().enumerate() {
+            match b {
+                b'"' | b'\\' => {
+                    self.0.write_str(&s[last..i])?;
+                    self.0.write_char('\\')?;
+                    self.0.write_char(b as char)?;
+                },
+                0x00..=0x1F => {
+                    self.0.write_str(&s[last..i])?;
+                    self.0.write_fmt(format_args!("\\u{:04x}", b))?;
+                },
+                _ => continue,
+            }
+
+            last = i + 1;
+        }
+
+        self.0.write_str(&s[last..])
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use core::fmt::Display;
+
+    use serde::{ser::SerializeMap, Serialize, Serializer};
+
+    use super::{HalLinks, JsonApiLinks, PageLinks};
+
+    impl<U: Display> Serialize for JsonApiLinks<'_, U> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let links: &PageLinks<U> = self.0;
+
+            let mut map = serializer.serialize_map(Some(5))?;
+
+            map.serialize_entry("first", &Str(&links.first))?;
+            map.serialize_entry("prev", &links.prev.as_ref().map(Str))?;
+            map.serialize_entry("self", &Str(&links.current))?;
+            map.serialize_entry("next", &links.next.as_ref().map(Str))?;
+            map.serialize_entry("last", &links.last.as_ref().map(Str))?;
+
+            map.end()
+        }
+    }
+
+    impl<U: Display> Serialize for HalLinks<'_, U> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut map = serializer.serialize_map(Some(self.0.iter().count()))?;
+
+            for (relation, url) in self.0.iter() {
+                map.serialize_entry(relation, &Href {
+                    href: Str(url)
+                })?;
+            }
+
+            map.end()
+        }
+    }
+
+    /// A value serialized as a string through `Display`.
+    struct Str<'a, U>(&'a U);
+
+    impl<U: Display> Serialize for Str<'_, U> {
+        #[inline]
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(self.0)
+        }
+    }
+
+    #[derive(Serialize)]
+    #[serde(bound = "")]
+    struct Href<'a, U: Display> {
+        href: Str<'a, U>,
+    }
+}
+
+// TODO ----------
+
+impl Paginator {
+    /// Create the links of the pages around the current page, with `url` mapping a page number to its URL.
+    pub fn links<U, F: Fn(NonZeroUsize) -> U>(&self, url: F) -> PageLinks<U> {
+        let current_page = self.current_page;
+
+        PageLinks {
+            first:   url(non_zero_page!(1)),
+            prev:    if current_page > 1 {
+                Some(url(non_zero_page!(current_page - 1)))
+            } else {
+                None
+            },
+            current: url(non_zero_page!(current_page)),
+            next:    if current_page < self.total_pages || self.has_more {
+                Some(url(non_zero_page!(current_page + 1)))
+            } else {
+                None
+            },
+            last:    if self.has_more { None } else { Some(url(non_zero_page!(self.total_pages))) },
+        }
+    }
+
+    /// Create the links of the pages around the current page, by setting the `page_param` query parameter of `base_url`. See `PageUrl`.
+    #[inline]
+    pub fn links_with_base_url<'a>(
+        &self,
+        base_url: &'a str,
+        page_param: &'a str,
+    ) -> PageLinks<PageHref<'a>> {
+        self.links_with_page_url(PageUrl::new(base_url).page_param(page_param))
+    }
+
+    /// Create the pagination numbers for the `meta` object of a JSON:API response or a HAL response.
+    #[inline]
+    pub const fn meta(&self) -> PageMeta {
+        PageMeta {
+            current_page: self.current_page,
+            total_pages:  if self.has_more { None } else { Some(self.total_pages) },
+            per_page:     self.per_page,
+            total_items:  self.total_items,
+        }
+    }
+}
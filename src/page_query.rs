@@ -0,0 +1,381 @@
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+use alloc::string::String;
+use core::fmt::{self, Display, Formatter};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+use crate::page_links::write_json_string;
+use crate::{page_url::decoded_eq, Paginator, PaginatorBuildError, PaginatorBuilder};
+
+/**
+    Settings for reading the page number and the page size from the query string of a request, such as `page=3&per_page=50`.
+
+    A missing (or empty) parameter takes its default value. In the strict mode, which is the default, invalid values are rejected with a `PageQueryError`. In the lenient mode, they are replaced or clamped to the nearest valid value instead.
+
+    ```rust
+    # use paginator::PageQuery;
+    let query = PageQuery::new().default_per_page(10).max_per_page(50);
+
+    let paginator = query.builder("?page=3&per_page=20&sort=name", 95).unwrap().build_paginator().unwrap();
+
+    assert_eq!(3, paginator.current_page());
+    assert_eq!(5, paginator.total_pages());
+
+    assert!(query.builder("page=7&per_page=20", 95).is_err());
+
+    let paginator = query.lenient(true).builder("page=7&per_page=500", 95).unwrap().build_paginator().unwrap();
+
+    assert_eq!(2, paginator.current_page());
+    assert_eq!(Some(50), paginator.per_page());
+    ```
+*/
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PageQuery<'a> {
+    pub(crate) page_param: &'a str,
+    per_page_param:        &'a str,
+    default_per_page:      usize,
+    max_per_page:          usize,
+    lenient:               bool,
+}
+
+impl PageQuery<'static> {
+    /// Create `PageQuery` settings which read the `page` and `per_page` parameters, with 20 items on each page by default and at most 100.
+    #[inline]
+    pub const fn new() -> PageQuery<'static> {
+        PageQuery {
+            page_param:       "page",
+            per_page_param:   "per_page",
+            default_per_page: 20,
+            max_per_page:     100,
+            lenient:          false,
+        }
+    }
+}
+
+impl Default for PageQuery<'static> {
+    #[inline]
+    fn default() -> Self {
+        PageQuery::new()
+    }
+}
+
+impl<'a> PageQuery<'a> {
+    /// Set the name of the page parameter.
+    #[inline]
+    pub const fn page_param(mut self, page_param: &'a str) -> PageQuery<'a> {
+        self.page_param = page_param;
+
+        self
+    }
+
+    /// Set the name of the page size parameter.
+    #[inline]
+    pub const fn per_page_param(mut self, per_page_param: &'a str) -> PageQuery<'a> {
+        self.per_page_param = per_page_param;
+
+        self
+    }
+
+    /// Set the number of items on each page when the page size parameter is missing.
+    #[inline]
+    pub const fn default_per_page(mut self, default_per_page: usize) -> PageQuery<'a> {
+        self.default_per_page = default_per_page;
+
+        self
+    }
+
+    /// Set the max number of items on each page which can be requested.
+    #[inline]
+    pub const fn max_per_page(mut self, max_per_page: usize) -> PageQuery<'a> {
+        self.max_per_page = max_per_page;
+
+        self
+    }
+
+    /// Set whether to clamp out-of-range values and ignore malformed ones, rather than returning an error.
+    #[inline]
+    pub const fn lenient(mut self, lenient: bool) -> PageQuery<'a> {
+        self.lenient = lenient;
+
+        self
+    }
+
+    /**
+        Read the page number and the page size from a query string. A leading `?` is ignored. If a parameter appears more than once, the first one is used.
+
+        The page number cannot be checked against the total number of pages here. Use `PageQuery::builder` for that.
+
+        ```rust
+        # use paginator::{PageParams, PageQuery, PageQueryError};
+        let query = PageQuery::new();
+
+        assert_eq!(Ok(PageParams { page: 4, per_page: 20 }), query.parse("page=4"));
+        assert_eq!(Err(PageQueryError::InvalidPage), query.parse("page=0"));
+        assert_eq!(Ok(PageParams { page: 1, per_page: 100 }), query.lenient(true).parse("page=abc&per_page=1000"));
+        ```
+    */
+    pub fn parse(&self, query: &str) -> Result<PageParams, PageQueryError> {
+        let query = query.strip_prefix('?').unwrap_or(query);
+        let query = query.split('#').next().unwrap_or("");
+
+        let mut page = None;
+        let mut per_page = None;
+
+        for pair in query.split('&') {
+            let mut pair = pair.splitn(2, '=');
+
+            let name = pair.next().unwrap_or("");
+            let value = pair.next().unwrap_or("");
+
+            if value.is_empty() {
+                continue;
+            }
+
+            if page.is_none() && decoded_eq(name, self.page_param) {
+                page = Some(value);
+            } else if per_page.is_none() && decoded_eq(name, self.per_page_param) {
+                per_page = Some(value);
+            }
+        }
+
+        let max_per_page = self.max_per_page.max(1);
+        let default_per_page = self.default_per_page.max(1).min(max_per_page);
+
+        let page = match page.map(parse_number) {
+            None => 1,
+            Some(Some(page)) if page > 0 => page,
+            Some(_) if self.lenient => 1,
+            Some(_) => return Err(PageQueryError::InvalidPage),
+        };
+
+        let per_page = match per_page.map(parse_number) {
+            None => default_per_page,
+            Some(Some(per_page)) if per_page > max_per_page => {
+                if self.lenient {
+                    max_per_page
+                } else {
+                    return Err(PageQueryError::PerPageTooLarge {
+                        per_page,
+                        max_per_page,
+                    });
+                }
+            },
+            Some(Some(per_page)) if per_page > 0 => per_page,
+            Some(Some(_)) if self.lenient => 1,
+            Some(_) if self.lenient => default_per_page,
+            Some(_) => return Err(PageQueryError::InvalidPerPage),
+        };
+
+        Ok(PageParams {
+            page,
+            per_page,
+        })
+    }
+
+    /**
+        Read the page number and the page size from a query string, and create a `PaginatorBuilder` for `total_items` items.
+
+        A page number beyond the last page is an error in the strict mode, and becomes the last page in the lenient mode.
+    */
+    pub fn builder(
+        &self,
+        query: &str,
+        total_items: usize,
+    ) -> Result<PaginatorBuilder, PageQueryError> {
+        self.pagination(query)?.builder(total_items)
+    }
+
+    /// Read the page number and the page size from a query string, before the number of items is known. See `Pagination`.
+    #[inline]
+    pub fn pagination(&self, query: &str) -> Result<Pagination, PageQueryError> {
+        Ok(Pagination {
+            params: self.parse(query)?, lenient: self.lenient
+        })
+    }
+}
+
+/// The page number and the page size read by `PageQuery::parse`. Both are at least 1.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PageParams {
+    /// The page number.
+    pub page:     usize,
+    /// The number of items on each page.
+    pub per_page: usize,
+}
+
+impl PageParams {
+    /// Return the number of items before the page, such as the `OFFSET` of an SQL query.
+    #[inline]
+    pub const fn offset(&self) -> usize {
+        (self.page - 1).saturating_mul(self.per_page)
+    }
+}
+
+/**
+    The pagination parameters of a request, read by `PageQuery::pagination` or by the extractors of web frameworks.
+
+    The page number is checked against the total number of pages when the number of items is known, in `Pagination::builder`.
+
+    ```rust
+    # use paginator::PageQuery;
+    let pagination = PageQuery::new().lenient(true).pagination("page=9&per_page=10").unwrap();
+
+    assert_eq!(9, pagination.params().page);
+
+    // count the items here
+
+    let paginator = pagination.paginator(35).unwrap();
+
+    assert_eq!(4, paginator.current_page());
+    assert_eq!(Some(30), paginator.offset());
+    ```
+*/
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Pagination {
+    params:  PageParams,
+    lenient: bool,
+}
+
+impl Pagination {
+    /// Return the page number and the page size, as requested.
+    #[inline]
+    pub const fn params(&self) -> PageParams {
+        self.params
+    }
+
+    /// Create a `PaginatorBuilder` for `total_items` items. A page number beyond the last page is an error in the strict mode, and becomes the last page in the lenient mode.
+    pub fn builder(&self, total_items: usize) -> Result<PaginatorBuilder, PageQueryError> {
+        let params = self.params;
+
+        let builder = PaginatorBuilder::from_items(total_items, params.per_page);
+        let total_pages = builder.total_pages;
+
+        let current_page = if params.page <= total_pages {
+            params.page
+        } else if self.lenient {
+            total_pages
+        } else {
+            return Err(PageQueryError::PageTooLarge {
+                page: params.page,
+                total_pages,
+            });
+        };
+
+        Ok(builder.current_page(current_page))
+    }
+
+    /// Create a `Paginator` for `total_items` items with the default settings of `PaginatorBuilder`.
+    #[inline]
+    pub fn paginator(&self, total_items: usize) -> Result<Paginator, PaginationRejection> {
+        Ok(self.builder(total_items)?.build_paginator()?)
+    }
+}
+
+/// An error of the pagination parameters of a request, used as the rejection of the `Pagination` extractors of web frameworks, which respond with `400 Bad Request` and a JSON body such as `{"error":"the page number should be a positive integer"}`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PaginationRejection {
+    /// The query string has invalid pagination parameters.
+    Query(PageQueryError),
+    /// The `Paginator` cannot be built.
+    Build(PaginatorBuildError),
+}
+
+impl PaginationRejection {
+    /// Format the error as a JSON object.
+    #[cfg(any(feature = "axum", feature = "actix-web"))]
+    pub(crate) fn to_json(self) -> String {
+        let mut json = String::from("{\"error\":");
+
+        write_json_string(&mut json, self).unwrap();
+        json.push('}');
+
+        json
+    }
+}
+
+impl From<PageQueryError> for PaginationRejection {
+    #[inline]
+    fn from(error: PageQueryError) -> Self {
+        PaginationRejection::Query(error)
+    }
+}
+
+impl From<PaginatorBuildError> for PaginationRejection {
+    #[inline]
+    fn from(error: PaginatorBuildError) -> Self {
+        PaginationRejection::Build(error)
+    }
+}
+
+impl Display for PaginationRejection {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            PaginationRejection::Query(error) => Display::fmt(error, f),
+            PaginationRejection::Build(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for PaginationRejection {}
+
+/// An error returned when the pagination parameters of a query string are invalid.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PageQueryError {
+    /// The page number is not a positive integer.
+    InvalidPage,
+    /// The page size is not a positive integer.
+    InvalidPerPage,
+    /// The page size is greater than the max page size.
+    PerPageTooLarge { per_page: usize, max_per_page: usize },
+    /// The page number is greater than the total number of pages.
+    PageTooLarge { page: usize, total_pages: usize },
+}
+
+impl Display for PageQueryError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            PageQueryError::InvalidPage => {
+                f.write_str("the page number should be a positive integer")
+            },
+            PageQueryError::InvalidPerPage => {
+                f.write_str("the page size should be a positive integer")
+            },
+            PageQueryError::PerPageTooLarge {
+                per_page,
+                max_per_page,
+            } => f.write_fmt(format_args!(
+                "{per_page} > {max_per_page} (per_page > max_per_page)",
+                per_page = per_page,
+                max_per_page = max_per_page
+            )),
+            PageQueryError::PageTooLarge {
+                page,
+                total_pages,
+            } => f.write_fmt(format_args!(
+                "{page} > {total_pages} (page > total_pages)",
+                page = page,
+                total_pages = total_pages
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for PageQueryError {}
+
+/// Parse a decimal number. Numbers too large for `usize` saturate, so they are out of range rather than malformed.
+fn parse_number(value: &str) -> Option<usize> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(
+        value
+            .bytes()
+            .fold(0usize, |n, b| n.saturating_mul(10).saturating_add(usize::from(b - b'0'))),
+    )
+}
@@ -0,0 +1,151 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::Paginator;
+
+/// A summary of the items on the current page, such as "Showing 21–30 of 95 results".
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PageSummary {
+    pub(crate) first_item:   usize,
+    pub(crate) last_item:    usize,
+    pub(crate) total_items:  usize,
+    pub(crate) current_page: usize,
+    pub(crate) total_pages:  usize,
+}
+
+impl PageSummary {
+    /// Return the 1-based number of the first item on the current page, or zero if there are no items.
+    #[inline]
+    pub const fn first_item(&self) -> usize {
+        self.first_item
+    }
+
+    /// Return the 1-based number of the last item on the current page, or zero if there are no items.
+    #[inline]
+    pub const fn last_item(&self) -> usize {
+        self.last_item
+    }
+
+    /// Return the number of items.
+    #[inline]
+    pub const fn total_items(&self) -> usize {
+        self.total_items
+    }
+
+    /// Return the current page number.
+    #[inline]
+    pub const fn current_page(&self) -> usize {
+        self.current_page
+    }
+
+    /// Return the total number of pages.
+    #[inline]
+    pub const fn total_pages(&self) -> usize {
+        self.total_pages
+    }
+
+    /**
+        Format the summary with a custom template.
+
+        The placeholders `{first}`, `{last}`, `{total}`, `{page}` and `{pages}` are replaced with the corresponding numbers. Other text is written as it is.
+
+        ```rust
+        # use paginator::PaginatorBuilder;
+        let paginator = PaginatorBuilder::from_items(95, 10).current_page(3).build_paginator().unwrap();
+        let summary = paginator.summary().unwrap();
+
+        assert_eq!("21-30 / 95", summary.format("{first}-{last} / {total}").to_string());
+        ```
+    */
+    #[inline]
+    pub const fn format<'a>(&'a self, template: &'a str) -> PageSummaryFormat<'a> {
+        PageSummaryFormat {
+            summary: self,
+            template,
+        }
+    }
+}
+
+impl Display for PageSummary {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self.total_items {
+            0 => f.write_str("No results"),
+            1 => f.write_str("Showing 1 of 1 result"),
+            _ if self.first_item == self.last_item => f.write_fmt(format_args!(
+                "Showing {} of {} results",
+                self.first_item, self.total_items
+            )),
+            _ => f.write_fmt(format_args!(
+                "Showing {}\u{2013}{} of {} results",
+                self.first_item, self.last_item, self.total_items
+            )),
+        }
+    }
+}
+
+/// A `PageSummary` formatted with a custom template. Created by `PageSummary::format`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PageSummaryFormat<'a> {
+    summary:  &'a PageSummary,
+    template: &'a str,
+}
+
+impl Display for PageSummaryFormat<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let mut template = self.template;
+
+        while let Some(start) = template.find('{') {
+            f.write_str(&template[..start])?;
+
+            let rest = &template[start..];
+
+            let value = match rest.find('}') {
+                Some(end) => match &rest[1..end] {
+                    "first" => Some((self.summary.first_item, end)),
+                    "last" => Some((self.summary.last_item, end)),
+                    "total" => Some((self.summary.total_items, end)),
+                    "page" => Some((self.summary.current_page, end)),
+                    "pages" => Some((self.summary.total_pages, end)),
+                    _ => None,
+                },
+                None => None,
+            };
+
+            match value {
+                Some((value, end)) => {
+                    f.write_fmt(format_args!("{}", value))?;
+
+                    template = &rest[end + 1..];
+                },
+                None => {
+                    f.write_str("{")?;
+
+                    template = &rest[1..];
+                },
+            }
+        }
+
+        f.write_str(template)
+    }
+}
+
+// TODO ----------
+
+impl Paginator {
+    /// Create a `PageSummary` of the current page, if `total_items` and `per_page` are known.
+    pub fn summary(&self) -> Option<PageSummary> {
+        let total_items = self.total_items?;
+        let range = self.item_range()?;
+
+        let (first_item, last_item) =
+            if range.start < range.end { (range.start + 1, range.end) } else { (0, 0) };
+
+        Some(PageSummary {
+            first_item,
+            last_item,
+            total_items,
+            current_page: self.current_page,
+            total_pages: self.total_pages,
+        })
+    }
+}
@@ -0,0 +1,281 @@
+use core::{
+    fmt::{self, Display, Formatter, Write},
+    num::NonZeroUsize,
+};
+
+use crate::{PageItem, PageLinks, Paginator};
+
+/**
+    A builder which turns page numbers into URLs by setting a query parameter of a base URL. The other query parameters (such as filters and the sort order) and the fragment of the base URL are kept.
+
+    An existing page parameter (or `per_page` parameter) in the base URL is replaced. Characters which are not allowed in a URL are percent-encoded.
+
+    ```rust
+    # use paginator::PageUrl;
+    let page_url = PageUrl::new("/search?q=rust+lang&page=9#results").per_page("per_page", 20).omit_first_page(true);
+
+    assert_eq!("/search?q=rust+lang&page=3&per_page=20#results", page_url.url(3.try_into().unwrap()).to_string());
+    assert_eq!("/search?q=rust+lang&per_page=20#results", page_url.url(1.try_into().unwrap()).to_string());
+    ```
+
+    It can be used as the URL function of `HtmlRenderer`, or to link `PageItem`s directly.
+
+    ```rust
+    # use paginator::{render::html::HtmlRenderer, PageUrl, Paginator};
+    let paginator = Paginator::builder(3).current_page(2).build_paginator().unwrap();
+    let page_url = PageUrl::new("/articles?tag=rust").page_param("p");
+
+    let mut html = String::new();
+
+    HtmlRenderer::new(|page| page_url.url(page)).render(&paginator, &mut html).unwrap();
+
+    assert!(html.contains("href=\"/articles?tag=rust&amp;p=3\""));
+
+    for item in paginator.page_items() {
+        if let Some(url) = page_url.item_url(&item) {
+            assert!(url.to_string().starts_with("/articles?tag=rust&p="));
+        }
+    }
+    ```
+*/
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PageUrl<'a> {
+    base_url:        &'a str,
+    page_param:      &'a str,
+    per_page:        Option<(&'a str, usize)>,
+    omit_first_page: bool,
+}
+
+impl<'a> PageUrl<'a> {
+    /// Create a `PageUrl` which sets the `page` query parameter of `base_url`.
+    #[inline]
+    pub const fn new(base_url: &'a str) -> PageUrl<'a> {
+        PageUrl {
+            base_url,
+            page_param: "page",
+            per_page: None,
+            omit_first_page: false,
+        }
+    }
+
+    /// Set the name of the page query parameter.
+    #[inline]
+    pub const fn page_param(mut self, page_param: &'a str) -> PageUrl<'a> {
+        self.page_param = page_param;
+
+        self
+    }
+
+    /// Also set the query parameter named `per_page_param` to the number of items on each page.
+    #[inline]
+    pub const fn per_page(mut self, per_page_param: &'a str, per_page: usize) -> PageUrl<'a> {
+        self.per_page = Some((per_page_param, per_page));
+
+        self
+    }
+
+    /// Set whether to leave out the page parameter for page 1, so the first page has the same URL as the base URL.
+    #[inline]
+    pub const fn omit_first_page(mut self, omit_first_page: bool) -> PageUrl<'a> {
+        self.omit_first_page = omit_first_page;
+
+        self
+    }
+
+    /// Create the URL of a page.
+    #[inline]
+    pub const fn url(&self, page: NonZeroUsize) -> PageHref<'a> {
+        PageHref {
+            page_url: *self,
+            page,
+        }
+    }
+
+    /// Create the URL of the page an item links to, if any. See `PageItem::page`.
+    #[inline]
+    pub fn item_url(&self, item: &PageItem) -> Option<PageHref<'a>> {
+        item.page().map(|page| self.url(page))
+    }
+
+    /// Whether an encoded query parameter name is one of the parameters set by this builder.
+    fn is_own_param(&self, encoded_name: &str) -> bool {
+        decoded_eq(encoded_name, self.page_param)
+            || matches!(self.per_page, Some((name, _)) if decoded_eq(encoded_name, name))
+    }
+}
+
+/// The URL of a page. Created by `PageUrl::url`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PageHref<'a> {
+    page_url: PageUrl<'a>,
+    page:     NonZeroUsize,
+}
+
+impl PageHref<'_> {
+    /// Return the page number.
+    #[inline]
+    pub const fn page(&self) -> NonZeroUsize {
+        self.page
+    }
+}
+
+impl Display for PageHref<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let page_url = &self.page_url;
+
+        let (url, fragment) = match page_url.base_url.find('#') {
+            Some(index) => (&page_url.base_url[..index], Some(&page_url.base_url[index + 1..])),
+            None => (page_url.base_url, None),
+        };
+
+        let (path, query) = match url.find('?') {
+            Some(index) => (&url[..index], &url[index + 1..]),
+            None => (url, ""),
+        };
+
+        Encode(f, is_url_byte).write_str(path)?;
+
+        let mut separator = '?';
+
+        for pair in query.split('&') {
+            if pair.is_empty() || page_url.is_own_param(pair.split('=').next().unwrap_or("")) {
+                continue;
+            }
+
+            f.write_char(separator)?;
+            Encode(f, is_url_byte).write_str(pair)?;
+
+            separator = '&';
+        }
+
+        if !(page_url.omit_first_page && self.page.get() == 1) {
+            f.write_char(separator)?;
+            Encode(f, is_unreserved_byte).write_str(page_url.page_param)?;
+            f.write_fmt(format_args!("={}", self.page))?;
+
+            separator = '&';
+        }
+
+        if let Some((per_page_param, per_page)) = page_url.per_page {
+            f.write_char(separator)?;
+            Encode(f, is_unreserved_byte).write_str(per_page_param)?;
+            f.write_fmt(format_args!("={}", per_page))?;
+        }
+
+        if let Some(fragment) = fragment {
+            f.write_char('#')?;
+            Encode(f, is_url_byte).write_str(fragment)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A writer which percent-encodes the bytes rejected by the predicate before passing them to the inner writer.
+struct Encode<'w, W>(&'w mut W, fn(u8) -> bool);
+
+impl<W: Write> Write for Encode<'_, W> {
+    fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
+        let mut last = 0;
+
+        for (i, b) in s.bytes().enumerate() {
+            if (self.1)(b) {
+                continue;
+            }
+
+            // Only ASCII bytes are kept, so the bytes between encoded bytes are always on character boundaries.
+            if last < i {
+                self.0.write_str(&s[last..i])?;
+            }
+
+            self.0.write_fmt(format_args!("%{:02X}", b))?;
+
+            last = i + 1;
+        }
+
+        self.0.write_str(&s[last..])
+    }
+}
+
+/// Whether the byte is an RFC 3986 unreserved character.
+#[inline]
+fn is_unreserved_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~')
+}
+
+/// Whether the byte can be written as it is in a path, a query or a fragment. Existing percent-encoded sequences are kept.
+#[inline]
+fn is_url_byte(b: u8) -> bool {
+    is_unreserved_byte(b)
+        || matches!(
+            b,
+            b'!' | b'$'
+                | b'&'
+                | b'\''
+                | b'('
+                | b')'
+                | b'*'
+                | b'+'
+                | b','
+                | b';'
+                | b'='
+                | b':'
+                | b'@'
+                | b'/'
+                | b'?'
+                | b'%'
+        )
+}
+
+/// Whether a percent-encoded query string component equals `plain` after decoding. `+` is decoded as a space.
+pub(crate) fn decoded_eq(encoded: &str, plain: &str) -> bool {
+    let mut encoded = encoded.bytes();
+    let mut plain = plain.bytes();
+
+    loop {
+        let b = match encoded.next() {
+            Some(b'%') => {
+                let mut hex = encoded.clone();
+
+                match (hex.next().and_then(hex_value), hex.next().and_then(hex_value)) {
+                    (Some(high), Some(low)) => {
+                        encoded = hex;
+
+                        high << 4 | low
+                    },
+                    _ => b'%',
+                }
+            },
+            Some(b'+') => b' ',
+            Some(b) => b,
+            None => return plain.next().is_none(),
+        };
+
+        if plain.next() != Some(b) {
+            return false;
+        }
+    }
+}
+
+#[inline]
+fn hex_value(b: u8) -> Option<u8> {
+    (b as char).to_digit(16).map(|value| value as u8)
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PageHref<'_> {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+// TODO ----------
+
+impl Paginator {
+    /// Create the links of the pages around the current page, with the URLs made by `page_url`.
+    #[inline]
+    pub fn links_with_page_url<'a>(&self, page_url: PageUrl<'a>) -> PageLinks<PageHref<'a>> {
+        self.links(|page| page_url.url(page))
+    }
+}
@@ -1,20 +1,13 @@
+#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
-use core::num::NonZeroUsize;
+use core::ops::Range;
 
-use crate::{PageItem, YesNoDepends};
-
-macro_rules! non_zero_page {
-    ($page:expr) => {{
-        let page = $page;
-        debug_assert!(page > 0);
-
-        // SAFETY: Every caller passes a page number that is greater than zero.
-        unsafe { NonZeroUsize::new_unchecked(page) }
-    }};
-}
+use crate::{page_items::ItemLayout, JumpSteps, Layout, PageItem, PageItems, YesNoDepends};
 
 /// Pagination settings for one current page.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "crate::PaginatorBuilder"))]
 pub struct Paginator {
     // total_pages and current_page is always bigger than 0 and current_page CANNOT be bigger than total_pages.
     pub(crate) total_pages:    usize,
@@ -24,6 +17,14 @@ pub struct Paginator {
     pub(crate) end_size:       usize,
     pub(crate) has_prev:       YesNoDepends,
     pub(crate) has_next:       YesNoDepends,
+    pub(crate) has_first:      YesNoDepends,
+    pub(crate) has_last:       YesNoDepends,
+    pub(crate) layout:         Layout,
+    pub(crate) fixed_width:    bool,
+    pub(crate) has_more:       bool,
+    pub(crate) jump_steps:     JumpSteps,
+    pub(crate) total_items:    Option<usize>,
+    pub(crate) per_page:       Option<usize>,
 }
 
 impl Paginator {
@@ -68,40 +69,266 @@ impl Paginator {
     pub const fn has_next(&self) -> YesNoDepends {
         self.has_next
     }
+
+    /// Return the setting for the first-page item.
+    #[inline]
+    pub const fn has_first(&self) -> YesNoDepends {
+        self.has_first
+    }
+
+    /// Return the setting for the last-page item.
+    #[inline]
+    pub const fn has_last(&self) -> YesNoDepends {
+        self.has_last
+    }
+
+    /// Return the algorithm which chooses the page links.
+    #[inline]
+    pub const fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Return whether the number of `PageItem`s is the same for every current page.
+    #[inline]
+    pub const fn fixed_width(&self) -> bool {
+        self.fixed_width
+    }
+
+    /// Return whether pages exist after `total_pages`, when the total number of pages is unknown.
+    #[inline]
+    pub const fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    /// Return the distances of the jump items.
+    #[inline]
+    pub const fn jump_steps(&self) -> JumpSteps {
+        self.jump_steps
+    }
+
+    /// Return the number of items, if known.
+    #[inline]
+    pub const fn total_items(&self) -> Option<usize> {
+        self.total_items
+    }
+
+    /// Return the number of items on each page, if known.
+    #[inline]
+    pub const fn per_page(&self) -> Option<usize> {
+        self.per_page
+    }
+}
+
+impl Paginator {
+    /// Return the index of the first item on the current page, if `per_page` is known.
+    #[inline]
+    pub fn offset(&self) -> Option<usize> {
+        self.per_page.and_then(|per_page| (self.current_page - 1).checked_mul(per_page))
+    }
+
+    /// Return the max number of items on the current page, if `per_page` is known.
+    #[inline]
+    pub const fn limit(&self) -> Option<usize> {
+        self.per_page
+    }
+
+    /**
+        Return the indices of the items on the current page, if `per_page` is known.
+
+        The range is clamped to `total_items` if it is known, so it can be shorter than `per_page` on the last page.
+
+        ```rust
+        # use paginator::PaginatorBuilder;
+        let paginator = PaginatorBuilder::from_items(95, 10).current_page(10).build_paginator().unwrap();
+
+        assert_eq!(Some(90), paginator.offset());
+        assert_eq!(Some(10), paginator.limit());
+        assert_eq!(Some(90..95), paginator.item_range());
+        ```
+    */
+    pub fn item_range(&self) -> Option<Range<usize>> {
+        let start = self.offset()?;
+        let mut end = start.saturating_add(self.per_page?);
+
+        if let Some(total_items) = self.total_items {
+            end = end.min(total_items);
+        }
+
+        Some(start.min(end)..end)
+    }
+
+    /// Return the page number which contains the item at `index`, if `per_page` is known and the item is within the pages.
+    #[inline]
+    pub fn page_of_item(&self, index: usize) -> Option<usize> {
+        if let Some(total_items) = self.total_items {
+            if index >= total_items {
+                return None;
+            }
+        }
+
+        let page = index / self.per_page? + 1;
+
+        if page <= self.total_pages {
+            Some(page)
+        } else {
+            None
+        }
+    }
 }
 
 impl Paginator {
     /// Create `PageItem`s.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
     pub fn paginate(&self) -> Vec<PageItem> {
-        // Reserve space for page items and up to two control items without overflowing usize.
-        let page_capacity = self.max_item_count.min(self.total_pages);
-        let control_capacity = (self.max_item_count - page_capacity).min(2);
-        let mut v = Vec::with_capacity(page_capacity + control_capacity);
+        self.page_items().collect()
+    }
+
+    /// Append `PageItem`s to an existing collection, such as a buffer reused across requests.
+    #[inline]
+    pub fn paginate_into<E: Extend<PageItem>>(&self, collection: &mut E) {
+        collection.extend(self.page_items());
+    }
+
+    /// Create an iterator which lazily yields the same `PageItem`s as `paginate` without allocating.
+    #[inline]
+    pub fn page_items(&self) -> PageItems {
+        PageItems::new(self.item_layout())
+    }
+
+    fn item_layout(&self) -> ItemLayout {
+        let (mut layout, items_counter) = self.control_layout();
+
+        if self.has_more {
+            let known_pages = self.known_pages();
+
+            known_pages.layout_pages(self.layout, &mut layout, items_counter - 1);
+
+            layout.push_more(known_pages.total_pages);
+        } else {
+            self.layout_pages(self.layout, &mut layout, items_counter);
+        }
+
+        layout.placeholders = self.placeholder_count(items_counter, layout.pages_len());
+
+        layout
+    }
+
+    /// Return the settings for laying out the pages which are known to exist, which end at the page after `total_pages` if `has_more` is set.
+    #[inline]
+    pub(crate) fn known_pages(&self) -> Paginator {
+        if self.has_more {
+            Paginator {
+                total_pages: self.total_pages + 1,
+                end_size: 0,
+                has_more: false,
+                ..self.clone()
+            }
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Lay out the control items, returning the number of items left for the pages.
+    pub(crate) fn control_layout(&self) -> (ItemLayout, usize) {
+        // The controls treat the page after `total_pages` as the last page if `has_more` is set.
+        let total_pages = self.total_pages + self.has_more as usize;
+
+        let mut layout = ItemLayout::new(total_pages, self.current_page);
         let mut items_counter = self.max_item_count;
 
-        let show_prev = self.has_prev.yes()
-            || (self.has_prev.depends() && self.current_page > 1 && self.total_pages > 2);
-        let show_next = self.has_next.yes()
-            || (self.has_next.depends()
-                && self.current_page < self.total_pages
-                && self.total_pages > 2);
+        // In the fixed-width mode, the `Depends` controls keep their positions on the edges as reserved items.
+        let not_first_page = self.fixed_width || self.current_page > 1;
+        let not_last_page = self.fixed_width || self.current_page < total_pages;
+        // The number of pages is unbounded if `has_more` is set.
+        let many_pages = total_pages > 2 || self.has_more;
+
+        let show_prev =
+            self.has_prev.yes() || (self.has_prev.depends() && not_first_page && many_pages);
+        let show_next =
+            self.has_next.yes() || (self.has_next.depends() && not_last_page && many_pages);
+
+        let show_first =
+            self.has_first.yes() || (self.has_first.depends() && not_first_page && many_pages);
+        // There is no last page to link to if the number of pages is unknown.
+        let show_last = self.has_last.yes()
+            || (self.has_last.depends() && not_last_page && many_pages && !self.has_more);
+
+        if show_first {
+            if self.current_page == 1 {
+                layout.first = Some(PageItem::ReservedFirst);
+            } else {
+                layout.first = Some(PageItem::First(non_zero_page!(1)));
+            }
+
+            items_counter -= 1;
+        }
+
+        if show_last {
+            if self.has_more || self.current_page == self.total_pages {
+                layout.last = Some(PageItem::ReservedLast);
+            } else {
+                layout.last = Some(PageItem::Last(non_zero_page!(self.total_pages)));
+            }
+
+            items_counter -= 1;
+        }
+
+        layout.jump_steps = self.jump_steps.effective(total_pages);
+
+        items_counter -= layout.jump_steps.len() * 2;
 
         if show_prev {
             let page = self.current_page - 1;
 
             if page == 0 {
-                v.push(PageItem::ReservedPrev);
+                layout.prev = Some(PageItem::ReservedPrev);
             } else {
-                v.push(PageItem::Prev(non_zero_page!(page)));
+                layout.prev = Some(PageItem::Prev(non_zero_page!(page)));
             }
 
             items_counter -= 1;
         }
 
         if show_next {
+            if self.current_page < total_pages {
+                layout.next = Some(PageItem::Next(non_zero_page!(self.current_page + 1)));
+            } else {
+                layout.next = Some(PageItem::ReservedNext);
+            }
+
             items_counter -= 1;
         }
 
+        (layout, items_counter)
+    }
+
+    /// Return the number of `PageItem::Placeholder` items which pad `page_item_count` items of pages in `items_counter` items.
+    #[inline]
+    pub(crate) fn placeholder_count(&self, items_counter: usize, page_item_count: usize) -> usize {
+        if self.fixed_width {
+            // A `PageItem::More` item is always laid out if `has_more` is set.
+            let max_page_item_count = self.total_pages + 2 * self.has_more as usize;
+
+            items_counter.min(max_page_item_count).saturating_sub(page_item_count)
+        } else {
+            0
+        }
+    }
+
+    /// Lay out the pages with a built-in `Layout` in `items_counter` items.
+    pub(crate) fn layout_pages(&self, kind: Layout, layout: &mut ItemLayout, items_counter: usize) {
+        match kind {
+            Layout::Logarithmic if self.total_pages > items_counter => {
+                layout.push_logarithmic(items_counter);
+            },
+            _ => self.layout_window(layout, items_counter),
+        }
+    }
+
+    /// Lay out a contiguous window around the current page, plus the start and end edges, in `items_counter` items.
+    fn layout_window(&self, layout: &mut ItemLayout, mut items_counter: usize) {
         let start_size = self.start_size.min(self.total_pages);
         let end_size = self.end_size.min(self.total_pages);
 
@@ -120,9 +347,7 @@ impl Paginator {
             if ignore_end {
                 items_counter -= end_size + 1;
 
-                for i in 1..=start_size {
-                    v.push(PageItem::Page(non_zero_page!(i)));
-                }
+                layout.push_pages(1, start_size);
 
                 let window_size = items_counter >> 1;
 
@@ -155,7 +380,7 @@ impl Paginator {
 
                     tp_e += shift;
                 } else {
-                    v.push(PageItem::Ignore);
+                    layout.push_ignore();
 
                     if tp_e >= end_boundary {
                         // Shift the visible window left when it reaches the reserved end section.
@@ -166,48 +391,36 @@ impl Paginator {
                     }
                 }
 
-                for i in hp_s..=hp_e {
-                    v.push(PageItem::Page(non_zero_page!(i)));
-                }
+                layout.push_pages(hp_s, hp_e);
 
-                v.push(PageItem::CurrentPage(non_zero_page!(self.current_page)));
+                layout.push_pages(self.current_page, self.current_page);
 
-                for i in tp_s..=tp_e {
-                    v.push(PageItem::Page(non_zero_page!(i)));
-                }
+                layout.push_pages(tp_s, tp_e);
 
                 if tp_e == end_boundary - 1 {
-                    v.push(PageItem::Page(non_zero_page!(end_boundary)));
+                    layout.push_pages(end_boundary, end_boundary);
                 } else {
-                    v.push(PageItem::Ignore);
+                    layout.push_ignore();
                 }
 
                 if end_size > 0 {
-                    for i in (end_boundary + 1)..=self.total_pages {
-                        v.push(PageItem::Page(non_zero_page!(i)));
-                    }
+                    layout.push_pages(end_boundary + 1, self.total_pages);
                 }
             } else {
                 if self.current_page < self.total_pages {
                     items_counter -= self.total_pages - self.current_page;
                 }
 
-                for i in 1..=start_size {
-                    v.push(PageItem::Page(non_zero_page!(i)));
-                }
+                layout.push_pages(1, start_size);
 
-                v.push(PageItem::Ignore);
+                layout.push_ignore();
 
-                for i in (self.current_page - items_counter)..=(self.current_page - 1) {
-                    v.push(PageItem::Page(non_zero_page!(i)));
-                }
+                layout.push_pages(self.current_page - items_counter, self.current_page - 1);
 
-                v.push(PageItem::CurrentPage(non_zero_page!(self.current_page)));
+                layout.push_pages(self.current_page, self.current_page);
 
                 if self.current_page < self.total_pages {
-                    for i in (self.current_page + 1)..=self.total_pages {
-                        v.push(PageItem::Page(non_zero_page!(i)));
-                    }
+                    layout.push_pages(self.current_page + 1, self.total_pages);
                 }
             }
         } else if ignore_end {
@@ -215,47 +428,27 @@ impl Paginator {
 
             items_counter -= self.current_page;
 
-            for i in 1..self.current_page {
-                v.push(PageItem::Page(non_zero_page!(i)));
-            }
+            layout.push_pages(1, self.current_page - 1);
 
-            v.push(PageItem::CurrentPage(non_zero_page!(self.current_page)));
+            layout.push_pages(self.current_page, self.current_page);
 
             let window_end = self.current_page + items_counter;
 
-            for i in (self.current_page + 1)..=window_end {
-                v.push(PageItem::Page(non_zero_page!(i)));
-            }
+            layout.push_pages(self.current_page + 1, window_end);
 
-            v.push(PageItem::Ignore);
+            layout.push_ignore();
 
             if end_size > 0 {
-                for i in (self.total_pages - end_size + 1)..=self.total_pages {
-                    v.push(PageItem::Page(non_zero_page!(i)));
-                }
+                layout.push_pages(self.total_pages - end_size + 1, self.total_pages);
             }
         } else {
-            for i in 1..self.current_page {
-                v.push(PageItem::Page(non_zero_page!(i)));
-            }
+            layout.push_pages(1, self.current_page - 1);
 
-            v.push(PageItem::CurrentPage(non_zero_page!(self.current_page)));
+            layout.push_pages(self.current_page, self.current_page);
 
             if self.current_page < self.total_pages {
-                for i in (self.current_page + 1)..=self.total_pages {
-                    v.push(PageItem::Page(non_zero_page!(i)));
-                }
+                layout.push_pages(self.current_page + 1, self.total_pages);
             }
         }
-
-        if show_next {
-            if self.current_page < self.total_pages {
-                v.push(PageItem::Next(non_zero_page!(self.current_page + 1)));
-            } else {
-                v.push(PageItem::ReservedNext);
-            }
-        }
-
-        v
     }
 }
@@ -2,7 +2,7 @@ use core::fmt::{self, Display, Formatter};
 #[cfg(feature = "std")]
 use std::error::Error;
 
-use crate::{Paginator, PaginatorIter, YesNoDepends};
+use crate::{JumpSteps, Layout, Paginator, PaginatorIter, YesNoDepends};
 
 /// An error returned when paginator settings are invalid.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -15,6 +15,10 @@ pub enum PaginatorBuildError {
     CurrentPageTooLarge { current_page: usize, total_pages: usize },
     /// The maximum item count is too small for the requested layout.
     MaxItemCountTooSmall { min_item_count: usize },
+    /// The number of items per page is zero.
+    PerPageZero,
+    /// The total number of pages does not fit the number of items.
+    TotalPagesMismatch { total_pages: usize, expected_total_pages: usize },
 }
 
 impl Display for PaginatorBuildError {
@@ -37,6 +41,16 @@ impl Display for PaginatorBuildError {
                 "max_item_count cannot be smaller than {}",
                 min_item_count
             )),
+            PaginatorBuildError::PerPageZero => f.write_str("per_page should not be zero"),
+            PaginatorBuildError::TotalPagesMismatch {
+                total_pages,
+                expected_total_pages,
+            } => f.write_fmt(format_args!(
+                "{total_pages} != {expected_total_pages} (total_pages does not fit total_items \
+                 and per_page)",
+                total_pages = total_pages,
+                expected_total_pages = expected_total_pages
+            )),
         }
     }
 }
@@ -46,6 +60,7 @@ impl Error for PaginatorBuildError {}
 
 /// A struct to create `Paginator` or `PaginatorIter`.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PaginatorBuilder {
     /// The number of pages.
     pub total_pages:    usize,
@@ -61,6 +76,22 @@ pub struct PaginatorBuilder {
     pub has_prev:       YesNoDepends,
     /// Whether to add the `PageItem::Next` item.
     pub has_next:       YesNoDepends,
+    /// Whether to add the `PageItem::First` item.
+    pub has_first:      YesNoDepends,
+    /// Whether to add the `PageItem::Last` item.
+    pub has_last:       YesNoDepends,
+    /// The algorithm which chooses the page links.
+    pub layout:         Layout,
+    /// Whether to keep the number of `PageItem`s the same for every current page. See `PaginatorBuilder::fixed_width`.
+    pub fixed_width:    bool,
+    /// Whether pages exist after `total_pages`, when the total number of pages is unknown. See `PaginatorBuilder::has_more`.
+    pub has_more:       bool,
+    /// The distances of the `PageItem::JumpBackward` and `PageItem::JumpForward` items. Steps which are not smaller than `total_pages` are not used.
+    pub jump_steps:     JumpSteps,
+    /// The number of items, if known.
+    pub total_items:    Option<usize>,
+    /// The number of items on each page, if known.
+    pub per_page:       Option<usize>,
 }
 
 impl PaginatorBuilder {
@@ -68,7 +99,7 @@ impl PaginatorBuilder {
         Create a new `PaginatorBuilder` with some default options.
 
         ```rust
-        # use paginator::{PaginatorBuilder, YesNoDepends};
+        # use paginator::{JumpSteps, Layout, PaginatorBuilder, YesNoDepends};
         # let total_pages = 10;
         PaginatorBuilder {
             total_pages, // this is input by the caller.
@@ -78,6 +109,14 @@ impl PaginatorBuilder {
             end_size: 1,
             has_prev: YesNoDepends::Depends,
             has_next: YesNoDepends::Depends,
+            has_first: YesNoDepends::No,
+            has_last: YesNoDepends::No,
+            layout: Layout::Window,
+            fixed_width: false,
+            has_more: false,
+            jump_steps: JumpSteps::new(),
+            total_items: None,
+            per_page: None,
         }
         # ;
         ```
@@ -92,9 +131,34 @@ impl PaginatorBuilder {
             end_size: 1,
             has_prev: YesNoDepends::Depends,
             has_next: YesNoDepends::Depends,
+            has_first: YesNoDepends::No,
+            has_last: YesNoDepends::No,
+            layout: Layout::Window,
+            fixed_width: false,
+            has_more: false,
+            jump_steps: JumpSteps::new(),
+            total_items: None,
+            per_page: None,
         }
     }
 
+    /**
+        Create a new `PaginatorBuilder` whose `total_pages` is computed from the number of items and the number of items on each page.
+
+        An empty result set still has one (empty) page.
+
+        ```rust
+        # use paginator::PaginatorBuilder;
+        assert_eq!(10, PaginatorBuilder::from_items(95, 10).total_pages);
+        assert_eq!(1, PaginatorBuilder::from_items(0, 10).total_pages);
+        ```
+    */
+    #[inline]
+    pub const fn from_items(total_items: usize, per_page: usize) -> PaginatorBuilder {
+        PaginatorBuilder::new(total_pages_for_items(total_items, per_page))
+            .items(total_items, per_page)
+    }
+
     /// Set the number of pages.
     #[inline]
     pub const fn total_pages(mut self, total_pages: usize) -> PaginatorBuilder {
@@ -150,20 +214,174 @@ impl PaginatorBuilder {
 
         self
     }
+
+    /// Set whether to add the `PageItem::First` item.
+    #[inline]
+    pub const fn has_first(mut self, has_first: YesNoDepends) -> PaginatorBuilder {
+        self.has_first = has_first;
+
+        self
+    }
+
+    /// Set whether to add the `PageItem::Last` item.
+    #[inline]
+    pub const fn has_last(mut self, has_last: YesNoDepends) -> PaginatorBuilder {
+        self.has_last = has_last;
+
+        self
+    }
+
+    /// Set the algorithm which chooses the page links.
+    #[inline]
+    pub const fn layout(mut self, layout: Layout) -> PaginatorBuilder {
+        self.layout = layout;
+
+        self
+    }
+
+    /**
+        Set whether to keep the number of `PageItem`s the same for every current page, so the pagination bar does not change its width while paging.
+
+        In this mode, the `Depends` controls always take their positions (as reserved items on the edges) when there are more than two pages, and `PageItem::Placeholder` items pad the pages when fewer than `max_item_count` items are laid out.
+
+        ```rust
+        # #[cfg(feature = "alloc")] {
+        # use paginator::{page_items_to_string, PaginatorBuilder};
+        let builder = PaginatorBuilder::new(8).max_item_count(7).fixed_width(true);
+
+        assert_eq!(
+            ") 1* 2 3 ... 8 >",
+            page_items_to_string(&builder.clone().current_page(1).build_paginator().unwrap().paginate())
+        );
+        assert_eq!(
+            "< 1 ... 4* ... 8 >",
+            page_items_to_string(&builder.current_page(4).build_paginator().unwrap().paginate())
+        );
+        # }
+        ```
+    */
+    #[inline]
+    pub const fn fixed_width(mut self, fixed_width: bool) -> PaginatorBuilder {
+        self.fixed_width = fixed_width;
+
+        self
+    }
+
+    /**
+        Create a new `PaginatorBuilder` for a list whose total number of pages is unknown, such as when counting the items is too expensive. Only the current page and whether there is a next page are needed.
+
+        The pages up to the current page are known to exist. See `PaginatorBuilder::has_more`.
+
+        ```rust
+        # #[cfg(feature = "alloc")] {
+        # use paginator::{page_items_to_string, PaginatorBuilder};
+        let paginator = PaginatorBuilder::unknown_total(9, true).build_paginator().unwrap();
+
+        assert_eq!("< 1 ... 7 8 9* 10 ... >", page_items_to_string(&paginator.paginate()));
+
+        let paginator = PaginatorBuilder::unknown_total(9, false).build_paginator().unwrap();
+
+        assert_eq!("< 1 ... 4 5 6 7 8 9*", page_items_to_string(&paginator.paginate()));
+        # }
+        ```
+    */
+    #[inline]
+    pub const fn unknown_total(current_page: usize, has_more: bool) -> PaginatorBuilder {
+        PaginatorBuilder::new(current_page).current_page(current_page).has_more(has_more)
+    }
+
+    /**
+        Set whether pages exist after `total_pages`. If so, `total_pages` is a lower bound of the number of pages, the page after it is known to exist, and the pages after that one are shown as a `PageItem::More` item.
+
+        There is no last page to link to, so `has_last` only adds a `PageItem::ReservedLast` item when it is `YesNoDepends::Yes`, and `end_size` is not used.
+
+        It is ignored if `total_pages` is `usize::MAX - 1` or greater, because the pages after the known ones cannot be numbered.
+    */
+    #[inline]
+    pub const fn has_more(mut self, has_more: bool) -> PaginatorBuilder {
+        self.has_more = has_more;
+
+        self
+    }
+
+    /// Set the distances of the `PageItem::JumpBackward` and `PageItem::JumpForward` items, such as `&[10, 100]`. See `JumpSteps::from_slice`. On the first page and the last page, the jumps which would stay on the current page become `PageItem::ReservedJumpBackward` and `PageItem::ReservedJumpForward` items.
+    #[inline]
+    pub const fn jump_steps(mut self, jump_steps: &[usize]) -> PaginatorBuilder {
+        self.jump_steps = JumpSteps::from_slice(jump_steps);
+
+        self
+    }
+
+    /// Set the number of items and the number of items on each page. The number of pages is computed from them.
+    #[inline]
+    pub const fn items(mut self, total_items: usize, per_page: usize) -> PaginatorBuilder {
+        self.total_pages = total_pages_for_items(total_items, per_page);
+        self.total_items = Some(total_items);
+        self.per_page = Some(per_page);
+
+        self
+    }
+
+    /// Set the number of items on each page without changing the number of pages.
+    #[inline]
+    pub const fn per_page(mut self, per_page: usize) -> PaginatorBuilder {
+        self.per_page = Some(per_page);
+
+        self
+    }
+}
+
+#[inline]
+const fn total_pages_for_items(total_items: usize, per_page: usize) -> usize {
+    if per_page == 0 {
+        0
+    } else if total_items == 0 {
+        1
+    } else {
+        (total_items - 1) / per_page + 1
+    }
 }
 
 impl PaginatorBuilder {
     fn compute_min_item_count(&self) -> usize {
-        match self.total_pages {
+        if self.has_more && self.total_pages > 0 && self.total_pages < usize::MAX - 1 {
+            // Lay out the pages which are known to exist without the end edge, plus a `PageItem::More` item.
+            let known_pages = PaginatorBuilder {
+                total_pages: self.total_pages + 1,
+                end_size: 0,
+                has_last: if self.has_last.yes() { YesNoDepends::Yes } else { YesNoDepends::No },
+                has_more: false,
+                ..self.clone()
+            };
+
+            return known_pages.compute_min_item_count() + 1;
+        }
+
+        // Each jump step adds a backward item and a forward item.
+        let jump_item_count = self.jump_steps.effective(self.total_pages).len() * 2;
+
+        let min_item_count = match self.total_pages {
             0 => 0,
-            1 | 2 => self.total_pages + self.has_prev.yes() as usize + self.has_next.yes() as usize,
+            1 | 2 => {
+                self.total_pages
+                    + self.has_prev.yes() as usize
+                    + self.has_next.yes() as usize
+                    + self.has_first.yes() as usize
+                    + self.has_last.yes() as usize
+            },
             _ => {
-                let start_size = self.start_size.min(self.total_pages);
-                let end_size = self.end_size.min(self.total_pages);
-                // Oversized layout settings intentionally follow Rust's normal overflow behavior.
-                let size = start_size + end_size;
-
-                let mut min_item_count = (size + 3).min(self.total_pages);
+                let mut min_item_count = match self.layout {
+                    Layout::Window => {
+                        let start_size = self.start_size.min(self.total_pages);
+                        let end_size = self.end_size.min(self.total_pages);
+                        // Oversized layout settings intentionally follow Rust's normal overflow behavior.
+                        let size = start_size + end_size;
+
+                        (size + 3).min(self.total_pages)
+                    },
+                    // The first page, an ignore marker, the current page, an ignore marker and the last page.
+                    Layout::Logarithmic => 5.min(self.total_pages),
+                };
 
                 if !self.has_prev.no() {
                     min_item_count += 1;
@@ -173,13 +391,40 @@ impl PaginatorBuilder {
                     min_item_count += 1;
                 }
 
+                if !self.has_first.no() {
+                    min_item_count += 1;
+                }
+
+                if !self.has_last.no() {
+                    min_item_count += 1;
+                }
+
                 min_item_count
             },
-        }
+        };
+
+        min_item_count + jump_item_count
     }
 
     #[inline]
     fn build_check_common(&self) -> Result<(), PaginatorBuildError> {
+        if let Some(per_page) = self.per_page {
+            if per_page == 0 {
+                return Err(PaginatorBuildError::PerPageZero);
+            }
+
+            if let Some(total_items) = self.total_items {
+                let expected_total_pages = total_pages_for_items(total_items, per_page);
+
+                if self.total_pages != expected_total_pages {
+                    return Err(PaginatorBuildError::TotalPagesMismatch {
+                        total_pages: self.total_pages,
+                        expected_total_pages,
+                    });
+                }
+            }
+        }
+
         if self.current_page == 0 {
             return Err(PaginatorBuildError::CurrentPageZero);
         }
@@ -219,6 +464,14 @@ impl PaginatorBuilder {
             end_size:       self.end_size,
             has_prev:       self.has_prev,
             has_next:       self.has_next,
+            has_first:      self.has_first,
+            has_last:       self.has_last,
+            layout:         self.layout,
+            fixed_width:    self.fixed_width,
+            has_more:       self.has_more && self.total_pages < usize::MAX - 1,
+            jump_steps:     self.jump_steps,
+            total_items:    self.total_items,
+            per_page:       self.per_page,
         })
     }
 
@@ -236,6 +489,14 @@ impl PaginatorBuilder {
             end_size:       self.end_size,
             has_prev:       self.has_prev,
             has_next:       self.has_next,
+            has_first:      self.has_first,
+            has_last:       self.has_last,
+            layout:         self.layout,
+            fixed_width:    self.fixed_width,
+            has_more:       self.has_more && self.total_pages < usize::MAX - 1,
+            jump_steps:     self.jump_steps,
+            total_items:    self.total_items,
+            per_page:       self.per_page,
         })
     }
 }
@@ -248,6 +509,39 @@ impl Paginator {
     pub fn builder(total_pages: usize) -> PaginatorBuilder {
         PaginatorBuilder::new(total_pages)
     }
+
+    /**
+        Create a `PaginatorBuilder` with the settings of this `Paginator`, in order to build a similar one.
+
+        ```rust
+        # use paginator::Paginator;
+        let paginator = Paginator::builder(20).current_page(10).build_paginator().unwrap();
+        let narrower = paginator.to_builder().max_item_count(7).build_paginator().unwrap();
+
+        assert_eq!(10, narrower.current_page());
+        assert_eq!(7, narrower.page_items().len());
+        ```
+    */
+    #[inline]
+    pub fn to_builder(&self) -> PaginatorBuilder {
+        PaginatorBuilder {
+            total_pages:    self.total_pages,
+            current_page:   self.current_page,
+            max_item_count: self.max_item_count,
+            start_size:     self.start_size,
+            end_size:       self.end_size,
+            has_prev:       self.has_prev,
+            has_next:       self.has_next,
+            has_first:      self.has_first,
+            has_last:       self.has_last,
+            layout:         self.layout,
+            fixed_width:    self.fixed_width,
+            has_more:       self.has_more,
+            jump_steps:     self.jump_steps,
+            total_items:    self.total_items,
+            per_page:       self.per_page,
+        }
+    }
 }
 
 impl PaginatorIter {
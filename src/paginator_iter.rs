@@ -1,6 +1,6 @@
 use core::iter::FusedIterator;
 
-use crate::{Paginator, YesNoDepends};
+use crate::{JumpSteps, Layout, Paginator, YesNoDepends};
 
 /// An iterator over paginator settings for consecutive current pages.
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -13,6 +13,14 @@ pub struct PaginatorIter {
     pub(crate) end_size:       usize,
     pub(crate) has_prev:       YesNoDepends,
     pub(crate) has_next:       YesNoDepends,
+    pub(crate) has_first:      YesNoDepends,
+    pub(crate) has_last:       YesNoDepends,
+    pub(crate) layout:         Layout,
+    pub(crate) fixed_width:    bool,
+    pub(crate) has_more:       bool,
+    pub(crate) jump_steps:     JumpSteps,
+    pub(crate) total_items:    Option<usize>,
+    pub(crate) per_page:       Option<usize>,
 }
 
 impl PaginatorIter {
@@ -26,6 +34,14 @@ impl PaginatorIter {
             end_size: self.end_size,
             has_prev: self.has_prev,
             has_next: self.has_next,
+            has_first: self.has_first,
+            has_last: self.has_last,
+            layout: self.layout,
+            fixed_width: self.fixed_width,
+            has_more: self.has_more,
+            jump_steps: self.jump_steps,
+            total_items: self.total_items,
+            per_page: self.per_page,
         }
     }
 }
@@ -153,6 +169,14 @@ impl Paginator {
             end_size:       self.end_size,
             has_prev:       self.has_prev,
             has_next:       self.has_next,
+            has_first:      self.has_first,
+            has_last:       self.has_last,
+            layout:         self.layout,
+            fixed_width:    self.fixed_width,
+            has_more:       self.has_more,
+            jump_steps:     self.jump_steps,
+            total_items:    self.total_items,
+            per_page:       self.per_page,
         }
     }
 }
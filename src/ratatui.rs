@@ -0,0 +1,243 @@
+/*!
+A [ratatui](https://docs.rs/ratatui) widget which renders a pagination bar in a terminal.
+
+The bar is rendered on the first row of the area. If it is wider than the area, `max_item_count` is reduced until the bar fits (or cannot be reduced any further). `PaginationBar::page_at` tells which page is under a cell, for handling mouse clicks.
+
+```rust
+use paginator::{ratatui::PaginationBar, Paginator};
+use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+let paginator = Paginator::builder(20).current_page(10).build_paginator().unwrap();
+let bar = PaginationBar::new(&paginator);
+
+let area = Rect::new(0, 0, 24, 1);
+let mut buffer = Buffer::empty(area);
+
+(&bar).render(area, &mut buffer);
+
+assert_eq!("« 1 … 9 10 11 … 20 »    ", buffer.content.iter().map(|cell| cell.symbol()).collect::<String>());
+assert_eq!(Some(11), bar.page_at(area, 11, 0));
+```
+*/
+
+use ::ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::Widget,
+};
+
+use crate::{PageItem, Paginator};
+
+/// A widget which renders the items of a `Paginator` with their `PageItem::label`s, separated by a space by default.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PaginationBar<'a> {
+    paginator:      &'a Paginator,
+    separator:      &'a str,
+    style:          Style,
+    page_style:     Style,
+    current_style:  Style,
+    disabled_style: Style,
+    ellipsis_style: Style,
+}
+
+impl<'a> PaginationBar<'a> {
+    /// Create a `PaginationBar` with the default styles. The current page is reversed and bold, reserved control positions are dark gray, and ellipses are dim.
+    #[inline]
+    pub const fn new(paginator: &'a Paginator) -> PaginationBar<'a> {
+        PaginationBar {
+            paginator,
+            separator: " ",
+            style: Style::new(),
+            page_style: Style::new(),
+            current_style: Style::new()
+                .add_modifier(Modifier::REVERSED)
+                .add_modifier(Modifier::BOLD),
+            disabled_style: Style::new().fg(Color::DarkGray),
+            ellipsis_style: Style::new().add_modifier(Modifier::DIM),
+        }
+    }
+
+    /// Set the text between items.
+    #[inline]
+    pub const fn separator(mut self, separator: &'a str) -> PaginationBar<'a> {
+        self.separator = separator;
+
+        self
+    }
+
+    /// Set the style of the whole area.
+    #[inline]
+    pub const fn style(mut self, style: Style) -> PaginationBar<'a> {
+        self.style = style;
+
+        self
+    }
+
+    /// Set the style of the items which link to a page, such as `PageItem::Page` and `PageItem::Next`.
+    #[inline]
+    pub const fn page_style(mut self, style: Style) -> PaginationBar<'a> {
+        self.page_style = style;
+
+        self
+    }
+
+    /// Set the style of `PageItem::CurrentPage`.
+    #[inline]
+    pub const fn current_style(mut self, style: Style) -> PaginationBar<'a> {
+        self.current_style = style;
+
+        self
+    }
+
+    /// Set the style of reserved control positions, such as `PageItem::ReservedPrev` and `PageItem::ReservedNext`.
+    #[inline]
+    pub const fn disabled_style(mut self, style: Style) -> PaginationBar<'a> {
+        self.disabled_style = style;
+
+        self
+    }
+
+    /// Set the style of `PageItem::Ignore` and `PageItem::More`.
+    #[inline]
+    pub const fn ellipsis_style(mut self, style: Style) -> PaginationBar<'a> {
+        self.ellipsis_style = style;
+
+        self
+    }
+
+    /// Return the page of the item at the cell `(x, y)` when the bar is rendered in `area`, such as the position of a mouse click. The current page, items which do not link to a page, and the separators return `None`. See `PageItem::page`.
+    pub fn page_at(&self, area: Rect, x: u16, y: u16) -> Option<usize> {
+        if y != area.y {
+            return None;
+        }
+
+        self.layout(area)
+            .into_iter()
+            .find(|(_, item_area)| x >= item_area.left() && x < item_area.right())
+            .filter(|(item, _)| !item.is_current())
+            .and_then(|(item, _)| item.page())
+            .map(|page| page.get())
+    }
+
+    /// Create the items which fit the width, and place them on the first row of the area. Items beyond the right edge are clipped.
+    fn layout(&self, area: Rect) -> Vec<(PageItem, Rect)> {
+        if area.is_empty() {
+            return Vec::new();
+        }
+
+        let separator_width = Span::raw(self.separator).width();
+
+        let mut x = area.left();
+        let mut layout = Vec::new();
+
+        for item in self.fit(usize::from(area.width), separator_width) {
+            if x >= area.right() {
+                break;
+            }
+
+            if !layout.is_empty() {
+                x = x.saturating_add(separator_width as u16).min(area.right());
+            }
+
+            let width = (label_width(&item) as u16).min(area.right() - x);
+
+            layout.push((item, Rect::new(x, area.y, width, 1)));
+
+            x += width;
+        }
+
+        layout
+    }
+
+    /// Create the items with the largest `max_item_count`, up to the one of the paginator, whose width is not greater than `width`.
+    fn fit(&self, width: usize, separator_width: usize) -> Vec<PageItem> {
+        // Every item is at least one column wide, so more items can never fit.
+        let max_fitting_count = (width + separator_width) / (1 + separator_width);
+
+        let mut paginator = self.paginator.clone();
+
+        if max_fitting_count < paginator.max_item_count() {
+            if let Ok(smaller) =
+                paginator.to_builder().max_item_count(max_fitting_count).build_paginator()
+            {
+                paginator = smaller;
+            }
+        }
+
+        loop {
+            let items = paginator.paginate();
+
+            let items_width = items.iter().map(label_width).sum::<usize>()
+                + separator_width * items.len().saturating_sub(1);
+
+            if items_width <= width {
+                return items;
+            }
+
+            match paginator
+                .to_builder()
+                .max_item_count(paginator.max_item_count().saturating_sub(1))
+                .build_paginator()
+            {
+                Ok(smaller) => paginator = smaller,
+                Err(_) => return items,
+            }
+        }
+    }
+
+    fn item_style(&self, item: &PageItem) -> Style {
+        if item.is_current() {
+            self.current_style
+        } else if item.is_disabled() {
+            self.disabled_style
+        } else if item.is_ellipsis() {
+            self.ellipsis_style
+        } else {
+            self.page_style
+        }
+    }
+}
+
+impl Widget for PaginationBar<'_> {
+    #[inline]
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl Widget for &PaginationBar<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+
+        let mut previous_right = None;
+
+        for (item, item_area) in self.layout(area) {
+            if let Some(right) = previous_right {
+                buf.set_stringn(
+                    right,
+                    area.y,
+                    self.separator,
+                    usize::from(item_area.x - right),
+                    self.style,
+                );
+            }
+
+            buf.set_stringn(
+                item_area.x,
+                item_area.y,
+                item.label().to_string(),
+                usize::from(item_area.width),
+                self.style.patch(self.item_style(&item)),
+            );
+
+            previous_right = Some(item_area.right());
+        }
+    }
+}
+
+#[inline]
+fn label_width(item: &PageItem) -> usize {
+    Span::raw(item.label().to_string()).width()
+}
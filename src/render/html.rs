@@ -0,0 +1,705 @@
+/*!
+Render `PageItem`s into HTML.
+
+The markup is written into any `core::fmt::Write`, so it can be used without std.
+
+```rust
+use paginator::{render::html::HtmlRenderer, Paginator};
+
+let paginator = Paginator::builder(3).current_page(2).build_paginator().unwrap();
+
+let mut html = String::new();
+
+HtmlRenderer::new(|page| format!("/page/{}", page)).render(&paginator, &mut html).unwrap();
+
+assert!(html.starts_with("<nav aria-label=\"Pagination\"><ul class=\"pagination\">"));
+assert!(html.contains("<li class=\"current\" aria-current=\"page\"><span aria-label=\"Page 2\">2</span></li>"));
+```
+*/
+
+use core::{
+    borrow::Borrow,
+    fmt::{self, Display, Formatter, Write},
+    num::NonZeroUsize,
+};
+
+use crate::{PageItem, Paginator};
+
+/// CSS classes of the generated elements. An empty string omits the class.
+///
+/// Classes of `<li>` elements are additive, while each kind of item has its own class for the `<a>` or `<span>` element inside.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct HtmlClasses<'a> {
+    /// The class of the `<nav>` element.
+    pub nav:           &'a str,
+    /// The class of the `<ul>` element.
+    pub list:          &'a str,
+    /// The class of every `<li>` element.
+    pub item:          &'a str,
+    /// The additional class of the `<li>` element of the current page.
+    pub item_current:  &'a str,
+    /// The additional class of the `<li>` element of a reserved control position, such as `PageItem::ReservedPrev`.
+    pub item_disabled: &'a str,
+    /// The additional class of the `<li>` element of a hidden range of pages.
+    pub item_ellipsis: &'a str,
+    /// The class of the link to a regular page.
+    pub page:          &'a str,
+    /// The class of the current page.
+    pub current:       &'a str,
+    /// The class of the previous-page item.
+    pub prev:          &'a str,
+    /// The class of the next-page item.
+    pub next:          &'a str,
+    /// The class of the first-page item.
+    pub first:         &'a str,
+    /// The class of the last-page item.
+    pub last:          &'a str,
+    /// The class of a jump item.
+    pub jump:          &'a str,
+    /// The class of a hidden range of pages.
+    pub ellipsis:      &'a str,
+    /// The additional class of a reserved control position, such as `PageItem::ReservedPrev`.
+    pub disabled:      &'a str,
+}
+
+impl HtmlClasses<'static> {
+    /// Create `HtmlClasses` with the default class names.
+    #[inline]
+    pub const fn new() -> HtmlClasses<'static> {
+        HtmlClasses {
+            nav:           "",
+            list:          "pagination",
+            item:          "",
+            item_current:  "current",
+            item_disabled: "disabled",
+            item_ellipsis: "ellipsis",
+            page:          "",
+            current:       "",
+            prev:          "",
+            next:          "",
+            first:         "",
+            last:          "",
+            jump:          "",
+            ellipsis:      "",
+            disabled:      "",
+        }
+    }
+
+    /// Create `HtmlClasses` for [Bootstrap](https://getbootstrap.com/docs/5.3/components/pagination/).
+    #[inline]
+    pub const fn bootstrap() -> HtmlClasses<'static> {
+        HtmlClasses {
+            nav:           "",
+            list:          "pagination",
+            item:          "page-item",
+            item_current:  "active",
+            item_disabled: "disabled",
+            item_ellipsis: "disabled",
+            page:          "page-link",
+            current:       "page-link",
+            prev:          "page-link",
+            next:          "page-link",
+            first:         "page-link",
+            last:          "page-link",
+            jump:          "page-link",
+            ellipsis:      "page-link",
+            disabled:      "",
+        }
+    }
+
+    /// Create `HtmlClasses` for [Bulma](https://bulma.io/documentation/components/pagination/).
+    ///
+    /// Bulma expects the previous-page and next-page items outside the list, and `aria-current` on the link of the current page, so use it with `HtmlRenderer::detached_controls` and `HtmlRenderer::current_as_link`.
+    #[inline]
+    pub const fn bulma() -> HtmlClasses<'static> {
+        HtmlClasses {
+            nav:           "pagination",
+            list:          "pagination-list",
+            item:          "",
+            item_current:  "",
+            item_disabled: "",
+            item_ellipsis: "",
+            page:          "pagination-link",
+            current:       "pagination-link is-current",
+            prev:          "pagination-previous",
+            next:          "pagination-next",
+            first:         "pagination-link",
+            last:          "pagination-link",
+            jump:          "pagination-link",
+            ellipsis:      "pagination-ellipsis",
+            disabled:      "is-disabled",
+        }
+    }
+
+    /// Create `HtmlClasses` with [Tailwind CSS](https://tailwindcss.com/) utility classes.
+    #[inline]
+    pub const fn tailwind() -> HtmlClasses<'static> {
+        HtmlClasses {
+            nav:           "",
+            list:          "inline-flex -space-x-px text-sm",
+            item:          "",
+            item_current:  "",
+            item_disabled: "",
+            item_ellipsis: "",
+            page:          "flex items-center justify-center px-3 h-8 leading-tight text-gray-500 \
+                            bg-white border border-gray-300 hover:bg-gray-100 hover:text-gray-700",
+            current:       "flex items-center justify-center px-3 h-8 text-blue-600 border \
+                            border-gray-300 bg-blue-50",
+            prev:          "flex items-center justify-center px-3 h-8 leading-tight text-gray-500 \
+                            bg-white border border-gray-300 rounded-s-lg hover:bg-gray-100 \
+                            hover:text-gray-700",
+            next:          "flex items-center justify-center px-3 h-8 leading-tight text-gray-500 \
+                            bg-white border border-gray-300 rounded-e-lg hover:bg-gray-100 \
+                            hover:text-gray-700",
+            first:         "flex items-center justify-center px-3 h-8 leading-tight text-gray-500 \
+                            bg-white border border-gray-300 hover:bg-gray-100 hover:text-gray-700",
+            last:          "flex items-center justify-center px-3 h-8 leading-tight text-gray-500 \
+                            bg-white border border-gray-300 hover:bg-gray-100 hover:text-gray-700",
+            jump:          "flex items-center justify-center px-3 h-8 leading-tight text-gray-500 \
+                            bg-white border border-gray-300 hover:bg-gray-100 hover:text-gray-700",
+            ellipsis:      "flex items-center justify-center px-3 h-8 leading-tight text-gray-500 \
+                            bg-white border border-gray-300",
+            disabled:      "pointer-events-none opacity-50",
+        }
+    }
+}
+
+impl Default for HtmlClasses<'static> {
+    #[inline]
+    fn default() -> Self {
+        HtmlClasses::new()
+    }
+}
+
+/// Texts of the generated elements.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct HtmlLabels<'a> {
+    /// The content of the previous-page item. It is written as raw HTML.
+    pub prev:               &'a str,
+    /// The content of the next-page item. It is written as raw HTML.
+    pub next:               &'a str,
+    /// The content of a hidden range of pages. It is written as raw HTML.
+    pub ellipsis:           &'a str,
+    /// The `aria-label` of the `<nav>` element.
+    pub nav_aria:           &'a str,
+    /// The `aria-label` of the previous-page item.
+    pub prev_aria:          &'a str,
+    /// The `aria-label` of the next-page item.
+    pub next_aria:          &'a str,
+    /// The content of the first-page item. It is written as raw HTML.
+    pub first:              &'a str,
+    /// The content of the last-page item. It is written as raw HTML.
+    pub last:               &'a str,
+    /// The `aria-label` of the first-page item.
+    pub first_aria:         &'a str,
+    /// The `aria-label` of the last-page item.
+    pub last_aria:          &'a str,
+    /// The content prefix of a backward jump item, followed by the step. It is written as raw HTML.
+    pub jump_backward:      &'a str,
+    /// The content prefix of a forward jump item, followed by the step. It is written as raw HTML.
+    pub jump_forward:       &'a str,
+    /// The `aria-label` prefix of a backward jump item, followed by the step.
+    pub jump_backward_aria: &'a str,
+    /// The `aria-label` prefix of a forward jump item, followed by the step.
+    pub jump_forward_aria:  &'a str,
+    /// The `aria-label` prefix of a page item, followed by the page number.
+    pub page_aria:          &'a str,
+}
+
+impl HtmlLabels<'static> {
+    /// Create `HtmlLabels` with the default English texts.
+    #[inline]
+    pub const fn new() -> HtmlLabels<'static> {
+        HtmlLabels {
+            prev:               "&laquo;",
+            next:               "&raquo;",
+            ellipsis:           "&hellip;",
+            nav_aria:           "Pagination",
+            prev_aria:          "Previous page",
+            next_aria:          "Next page",
+            first:              "&laquo; First",
+            last:               "Last &raquo;",
+            first_aria:         "First page",
+            last_aria:          "Last page",
+            jump_backward:      "&minus;",
+            jump_forward:       "+",
+            jump_backward_aria: "Jump back",
+            jump_forward_aria:  "Jump forward",
+            page_aria:          "Page",
+        }
+    }
+}
+
+impl Default for HtmlLabels<'static> {
+    #[inline]
+    fn default() -> Self {
+        HtmlLabels::new()
+    }
+}
+
+/// A renderer which writes `PageItem`s as a `<nav><ul><li>` list.
+///
+/// The `url` closure turns a page number into the `href` of its link. Its output is escaped.
+#[derive(Debug, Clone)]
+pub struct HtmlRenderer<'a, F> {
+    url:               F,
+    classes:           HtmlClasses<'a>,
+    labels:            HtmlLabels<'a>,
+    detached_controls: bool,
+    current_as_link:   bool,
+}
+
+impl<F> HtmlRenderer<'static, F> {
+    /// Create an `HtmlRenderer` with the default classes and labels.
+    #[inline]
+    pub const fn new(url: F) -> HtmlRenderer<'static, F> {
+        HtmlRenderer {
+            url,
+            classes: HtmlClasses::new(),
+            labels: HtmlLabels::new(),
+            detached_controls: false,
+            current_as_link: false,
+        }
+    }
+
+    /// Create an `HtmlRenderer` which generates the markup of Bootstrap.
+    #[inline]
+    pub const fn bootstrap(url: F) -> HtmlRenderer<'static, F> {
+        HtmlRenderer {
+            url,
+            classes: HtmlClasses::bootstrap(),
+            labels: HtmlLabels::new(),
+            detached_controls: false,
+            current_as_link: false,
+        }
+    }
+
+    /// Create an `HtmlRenderer` which generates the markup of Bulma.
+    #[inline]
+    pub const fn bulma(url: F) -> HtmlRenderer<'static, F> {
+        HtmlRenderer {
+            url,
+            classes: HtmlClasses::bulma(),
+            labels: HtmlLabels::new(),
+            detached_controls: true,
+            current_as_link: true,
+        }
+    }
+
+    /// Create an `HtmlRenderer` which generates the markup styled with Tailwind CSS.
+    #[inline]
+    pub const fn tailwind(url: F) -> HtmlRenderer<'static, F> {
+        HtmlRenderer {
+            url,
+            classes: HtmlClasses::tailwind(),
+            labels: HtmlLabels::new(),
+            detached_controls: false,
+            current_as_link: false,
+        }
+    }
+}
+
+impl<'a, F> HtmlRenderer<'a, F> {
+    /// Set the CSS classes.
+    #[inline]
+    pub fn classes<'b>(self, classes: HtmlClasses<'b>) -> HtmlRenderer<'b, F>
+    where
+        'a: 'b, {
+        HtmlRenderer {
+            url: self.url,
+            classes,
+            labels: self.labels,
+            detached_controls: self.detached_controls,
+            current_as_link: self.current_as_link,
+        }
+    }
+
+    /// Set the texts.
+    #[inline]
+    pub fn labels<'b>(self, labels: HtmlLabels<'b>) -> HtmlRenderer<'b, F>
+    where
+        'a: 'b, {
+        HtmlRenderer {
+            url: self.url,
+            classes: self.classes,
+            labels,
+            detached_controls: self.detached_controls,
+            current_as_link: self.current_as_link,
+        }
+    }
+
+    /// Set whether to write the previous-page and next-page items directly in the `<nav>` element, before the `<ul>` element, instead of inside it.
+    #[inline]
+    pub fn detached_controls(mut self, detached_controls: bool) -> HtmlRenderer<'a, F> {
+        self.detached_controls = detached_controls;
+
+        self
+    }
+
+    /// Set whether to write the current page as a link with `aria-current`, instead of a `<span>` element in an `<li>` element with `aria-current`.
+    #[inline]
+    pub fn current_as_link(mut self, current_as_link: bool) -> HtmlRenderer<'a, F> {
+        self.current_as_link = current_as_link;
+
+        self
+    }
+}
+
+impl<'a, F, U> HtmlRenderer<'a, F>
+where
+    F: Fn(NonZeroUsize) -> U,
+    U: Display,
+{
+    /// Render the `PageItem`s of a paginator.
+    #[inline]
+    pub fn render<W: Write>(&self, paginator: &Paginator, out: &mut W) -> Result<(), fmt::Error> {
+        self.render_items(paginator.page_items(), out)
+    }
+
+    /// Render the given `PageItem`s. The items are iterated twice if `detached_controls` is set.
+    pub fn render_items<W: Write, I>(&self, items: I, out: &mut W) -> Result<(), fmt::Error>
+    where
+        I: IntoIterator,
+        I::IntoIter: Clone,
+        I::Item: Borrow<PageItem>, {
+        let items = items.into_iter();
+
+        out.write_str("<nav")?;
+        write_class(out, self.classes.nav, "")?;
+        write_attr(out, "aria-label", self.labels.nav_aria)?;
+        out.write_char('>')?;
+
+        // Detached controls are written before the list, directly inside the `<nav>` element, so the other items stay in one list.
+        if self.detached_controls {
+            for item in items.clone() {
+                let item = *item.borrow();
+
+                if is_prev_or_next(item) {
+                    self.write_item(out, item, false)?;
+                }
+            }
+        }
+
+        let mut list_open = false;
+
+        for item in items {
+            let item = *item.borrow();
+
+            if self.detached_controls && is_prev_or_next(item) {
+                continue;
+            }
+
+            if !list_open {
+                out.write_str("<ul")?;
+                write_class(out, self.classes.list, "")?;
+                out.write_char('>')?;
+
+                list_open = true;
+            }
+
+            self.write_item(out, item, true)?;
+        }
+
+        if list_open {
+            out.write_str("</ul>")?;
+        }
+
+        out.write_str("</nav>")
+    }
+
+    fn write_item<W: Write>(
+        &self,
+        out: &mut W,
+        item: PageItem,
+        in_list: bool,
+    ) -> Result<(), fmt::Error> {
+        let classes = &self.classes;
+        let labels = &self.labels;
+
+        if in_list {
+            let li_class = match item {
+                PageItem::Page(_) => "",
+                PageItem::CurrentPage(_) => classes.item_current,
+                PageItem::Ignore {
+                    ..
+                }
+                | PageItem::More {
+                    ..
+                } => classes.item_ellipsis,
+                PageItem::Prev(_) | PageItem::Next(_) => "",
+                PageItem::ReservedPrev | PageItem::ReservedNext => classes.item_disabled,
+                PageItem::First(_) | PageItem::Last(_) => "",
+                PageItem::ReservedFirst | PageItem::ReservedLast => classes.item_disabled,
+                PageItem::Placeholder => classes.item_disabled,
+                PageItem::JumpBackward {
+                    ..
+                }
+                | PageItem::JumpForward {
+                    ..
+                } => "",
+                PageItem::ReservedJumpBackward {
+                    ..
+                }
+                | PageItem::ReservedJumpForward {
+                    ..
+                } => classes.item_disabled,
+            };
+
+            out.write_str("<li")?;
+            write_class(out, classes.item, li_class)?;
+
+            if matches!(item, PageItem::CurrentPage(_)) && !self.current_as_link {
+                out.write_str(" aria-current=\"page\"")?;
+            }
+
+            out.write_char('>')?;
+        }
+
+        match item {
+            PageItem::Page(page) => {
+                self.write_link(out, page, classes.page, Labeled(labels.page_aria, page), page)?;
+            },
+            PageItem::CurrentPage(page) if self.current_as_link => {
+                out.write_str("<a")?;
+                write_class(out, classes.current, "")?;
+                write_attr(out, "href", (self.url)(page))?;
+                write_attr(out, "aria-label", Labeled(labels.page_aria, page))?;
+                out.write_fmt(format_args!(" aria-current=\"page\">{}</a>", page))?;
+            },
+            PageItem::CurrentPage(page) => {
+                self.write_span(
+                    out,
+                    classes.current,
+                    false,
+                    Some(Labeled(labels.page_aria, page)),
+                    page,
+                )?;
+            },
+            PageItem::Ignore {
+                ..
+            }
+            | PageItem::More {
+                ..
+            } => {
+                self.write_span(out, classes.ellipsis, false, None::<&str>, Raw(labels.ellipsis))?;
+            },
+            PageItem::Prev(page) => {
+                self.write_link(out, page, classes.prev, labels.prev_aria, Raw(labels.prev))?;
+            },
+            PageItem::Next(page) => {
+                self.write_link(out, page, classes.next, labels.next_aria, Raw(labels.next))?;
+            },
+            PageItem::ReservedPrev => {
+                self.write_span(out, classes.prev, true, Some(labels.prev_aria), Raw(labels.prev))?;
+            },
+            PageItem::ReservedNext => {
+                self.write_span(out, classes.next, true, Some(labels.next_aria), Raw(labels.next))?;
+            },
+            PageItem::First(page) => {
+                self.write_link(out, page, classes.first, labels.first_aria, Raw(labels.first))?;
+            },
+            PageItem::Last(page) => {
+                self.write_link(out, page, classes.last, labels.last_aria, Raw(labels.last))?;
+            },
+            PageItem::ReservedFirst => {
+                self.write_span(
+                    out,
+                    classes.first,
+                    true,
+                    Some(labels.first_aria),
+                    Raw(labels.first),
+                )?;
+            },
+            PageItem::ReservedLast => {
+                self.write_span(out, classes.last, true, Some(labels.last_aria), Raw(labels.last))?;
+            },
+            PageItem::Placeholder => {
+                self.write_span(out, classes.page, true, None::<&str>, "")?;
+            },
+            PageItem::JumpBackward {
+                page,
+                step,
+            } => {
+                self.write_link(
+                    out,
+                    page,
+                    classes.jump,
+                    Labeled(labels.jump_backward_aria, step),
+                    Raw(labels.jump_backward).followed_by(step),
+                )?;
+            },
+            PageItem::JumpForward {
+                page,
+                step,
+            } => {
+                self.write_link(
+                    out,
+                    page,
+                    classes.jump,
+                    Labeled(labels.jump_forward_aria, step),
+                    Raw(labels.jump_forward).followed_by(step),
+                )?;
+            },
+            PageItem::ReservedJumpBackward {
+                step,
+            } => {
+                self.write_span(
+                    out,
+                    classes.jump,
+                    true,
+                    Some(Labeled(labels.jump_backward_aria, step)),
+                    Raw(labels.jump_backward).followed_by(step),
+                )?;
+            },
+            PageItem::ReservedJumpForward {
+                step,
+            } => {
+                self.write_span(
+                    out,
+                    classes.jump,
+                    true,
+                    Some(Labeled(labels.jump_forward_aria, step)),
+                    Raw(labels.jump_forward).followed_by(step),
+                )?;
+            },
+        }
+
+        if in_list {
+            out.write_str("</li>")?;
+        }
+
+        Ok(())
+    }
+
+    fn write_link<W: Write>(
+        &self,
+        out: &mut W,
+        page: NonZeroUsize,
+        class: &str,
+        aria_label: impl Display,
+        content: impl Display,
+    ) -> Result<(), fmt::Error> {
+        out.write_str("<a")?;
+        write_class(out, class, "")?;
+        write_attr(out, "href", (self.url)(page))?;
+        write_attr(out, "aria-label", aria_label)?;
+        out.write_fmt(format_args!(">{}</a>", content))
+    }
+
+    fn write_span<W: Write, A: Display, C: Display>(
+        &self,
+        out: &mut W,
+        class: &str,
+        disabled: bool,
+        aria_label: Option<A>,
+        content: C,
+    ) -> Result<(), fmt::Error> {
+        out.write_str("<span")?;
+        write_class(out, class, if disabled { self.classes.disabled } else { "" })?;
+
+        if disabled {
+            out.write_str(" aria-disabled=\"true\"")?;
+        }
+
+        match aria_label {
+            Some(aria_label) => write_attr(out, "aria-label", aria_label)?,
+            None => out.write_str(" aria-hidden=\"true\"")?,
+        }
+
+        out.write_fmt(format_args!(">{}</span>", content))
+    }
+}
+
+/// A label prefix followed by a number, such as the `aria-label` of a page item.
+struct Labeled<'a, T>(&'a str, T);
+
+impl<T: Display> Display for Labeled<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        if self.0.is_empty() {
+            f.write_fmt(format_args!("{}", self.1))
+        } else {
+            f.write_fmt(format_args!("{} {}", self.0, self.1))
+        }
+    }
+}
+
+/// A string which is already HTML.
+struct Raw<'a>(&'a str);
+
+impl<'a> Raw<'a> {
+    /// Write a number directly after the HTML.
+    #[inline]
+    fn followed_by<T: Display>(self, value: T) -> RawFollowedBy<'a, T> {
+        RawFollowedBy(self.0, value)
+    }
+}
+
+impl Display for Raw<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str(self.0)
+    }
+}
+
+/// A string which is already HTML, followed by a number.
+struct RawFollowedBy<'a, T>(&'a str, T);
+
+impl<T: Display> Display for RawFollowedBy<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_fmt(format_args!("{}{}", self.0, self.1))
+    }
+}
+
+/// A writer which escapes HTML special characters before passing them to the inner writer.
+struct Escape<'w, W>(&'w mut W);
+
+impl<W: Write> Write for Escape<'_, W> {
+    fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
+        let mut last = 0;
+
+        for (i, b) in s.bytes().enumerate() {
+            let escaped = match b {
+                b'&' => "&amp;",
+                b'<' => "&lt;",
+                b'>' => "&gt;",
+                b'"' => "&quot;",
+                b'\'' => "&#x27;",
+                _ => continue,
+            };
+
+            self.0.write_str(&s[last..i])?;
+            self.0.write_str(escaped)?;
+
+            last = i + 1;
+        }
+
+        self.0.write_str(&s[last..])
+    }
+}
+
+/// Whether the item is written before the list when the controls are detached.
+#[inline]
+fn is_prev_or_next(item: PageItem) -> bool {
+    matches!(
+        item,
+        PageItem::Prev(_) | PageItem::Next(_) | PageItem::ReservedPrev | PageItem::ReservedNext
+    )
+}
+
+#[inline]
+fn write_attr<W: Write>(out: &mut W, name: &str, value: impl Display) -> Result<(), fmt::Error> {
+    out.write_fmt(format_args!(" {}=\"", name))?;
+    Escape(out).write_fmt(format_args!("{}", value))?;
+    out.write_char('"')
+}
+
+fn write_class<W: Write>(out: &mut W, class: &str, extra: &str) -> Result<(), fmt::Error> {
+    match (class.is_empty(), extra.is_empty()) {
+        (true, true) => Ok(()),
+        (false, true) => write_attr(out, "class", class),
+        (true, false) => write_attr(out, "class", extra),
+        (false, false) => write_attr(out, "class", format_args!("{} {}", class, extra)),
+    }
+}
@@ -0,0 +1,3 @@
+//! Renderers which turn `PageItem`s into markup.
+
+pub mod html;
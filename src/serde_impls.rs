@@ -0,0 +1,222 @@
+//! `serde` support. `PageItem` is (de)serialized through `PageItemRepr`, `Paginator` is deserialized through `PaginatorBuilder` so its settings are checked, and `JumpSteps` is a sequence of steps.
+
+use core::{convert::TryFrom, fmt, num::NonZeroUsize};
+
+use serde::{
+    de::{SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{JumpSteps, PageItem, Paginator, PaginatorBuildError, PaginatorBuilder};
+
+/// The serialized form of `PageItem`, such as `{"type":"page","page":3}`.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum PageItemRepr {
+    Page { page: NonZeroUsize },
+    CurrentPage { page: NonZeroUsize },
+    Ignore { start: NonZeroUsize, end: NonZeroUsize },
+    More { start: NonZeroUsize },
+    Prev { page: NonZeroUsize },
+    Next { page: NonZeroUsize },
+    ReservedPrev,
+    ReservedNext,
+    First { page: NonZeroUsize },
+    Last { page: NonZeroUsize },
+    ReservedFirst,
+    ReservedLast,
+    Placeholder,
+    JumpBackward { page: NonZeroUsize, step: usize },
+    JumpForward { page: NonZeroUsize, step: usize },
+    ReservedJumpBackward { step: usize },
+    ReservedJumpForward { step: usize },
+}
+
+impl From<PageItem> for PageItemRepr {
+    #[inline]
+    fn from(item: PageItem) -> Self {
+        match item {
+            PageItem::Page(page) => PageItemRepr::Page {
+                page,
+            },
+            PageItem::CurrentPage(page) => PageItemRepr::CurrentPage {
+                page,
+            },
+            PageItem::Ignore {
+                start,
+                end,
+            } => PageItemRepr::Ignore {
+                start,
+                end,
+            },
+            PageItem::More {
+                start,
+            } => PageItemRepr::More {
+                start,
+            },
+            PageItem::Prev(page) => PageItemRepr::Prev {
+                page,
+            },
+            PageItem::Next(page) => PageItemRepr::Next {
+                page,
+            },
+            PageItem::ReservedPrev => PageItemRepr::ReservedPrev,
+            PageItem::ReservedNext => PageItemRepr::ReservedNext,
+            PageItem::First(page) => PageItemRepr::First {
+                page,
+            },
+            PageItem::Last(page) => PageItemRepr::Last {
+                page,
+            },
+            PageItem::ReservedFirst => PageItemRepr::ReservedFirst,
+            PageItem::ReservedLast => PageItemRepr::ReservedLast,
+            PageItem::Placeholder => PageItemRepr::Placeholder,
+            PageItem::JumpBackward {
+                page,
+                step,
+            } => PageItemRepr::JumpBackward {
+                page,
+                step,
+            },
+            PageItem::JumpForward {
+                page,
+                step,
+            } => PageItemRepr::JumpForward {
+                page,
+                step,
+            },
+            PageItem::ReservedJumpBackward {
+                step,
+            } => PageItemRepr::ReservedJumpBackward {
+                step,
+            },
+            PageItem::ReservedJumpForward {
+                step,
+            } => PageItemRepr::ReservedJumpForward {
+                step,
+            },
+        }
+    }
+}
+
+impl TryFrom<PageItemRepr> for PageItem {
+    type Error = &'static str;
+
+    #[inline]
+    fn try_from(item: PageItemRepr) -> Result<Self, Self::Error> {
+        let item = match item {
+            PageItemRepr::Page {
+                page,
+            } => PageItem::Page(page),
+            PageItemRepr::CurrentPage {
+                page,
+            } => PageItem::CurrentPage(page),
+            PageItemRepr::Ignore {
+                start,
+                end,
+            } => {
+                if start > end {
+                    return Err("the start of an ignore item should not be greater than its end");
+                }
+
+                PageItem::Ignore {
+                    start,
+                    end,
+                }
+            },
+            PageItemRepr::More {
+                start,
+            } => PageItem::More {
+                start,
+            },
+            PageItemRepr::Prev {
+                page,
+            } => PageItem::Prev(page),
+            PageItemRepr::Next {
+                page,
+            } => PageItem::Next(page),
+            PageItemRepr::ReservedPrev => PageItem::ReservedPrev,
+            PageItemRepr::ReservedNext => PageItem::ReservedNext,
+            PageItemRepr::First {
+                page,
+            } => PageItem::First(page),
+            PageItemRepr::Last {
+                page,
+            } => PageItem::Last(page),
+            PageItemRepr::ReservedFirst => PageItem::ReservedFirst,
+            PageItemRepr::ReservedLast => PageItem::ReservedLast,
+            PageItemRepr::Placeholder => PageItem::Placeholder,
+            PageItemRepr::JumpBackward {
+                page,
+                step,
+            } => PageItem::JumpBackward {
+                page,
+                step,
+            },
+            PageItemRepr::JumpForward {
+                page,
+                step,
+            } => PageItem::JumpForward {
+                page,
+                step,
+            },
+            PageItemRepr::ReservedJumpBackward {
+                step,
+            } => PageItem::ReservedJumpBackward {
+                step,
+            },
+            PageItemRepr::ReservedJumpForward {
+                step,
+            } => PageItem::ReservedJumpForward {
+                step,
+            },
+        };
+
+        Ok(item)
+    }
+}
+
+impl TryFrom<PaginatorBuilder> for Paginator {
+    type Error = PaginatorBuildError;
+
+    #[inline]
+    fn try_from(builder: PaginatorBuilder) -> Result<Self, Self::Error> {
+        builder.build_paginator()
+    }
+}
+
+impl Serialize for JumpSteps {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+impl<'de> Deserialize<'de> for JumpSteps {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct JumpStepsVisitor;
+
+        impl<'de> Visitor<'de> for JumpStepsVisitor {
+            type Value = JumpSteps;
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a sequence of jump steps")
+            }
+
+            #[inline]
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut jump_steps = JumpSteps::new();
+
+                while let Some(step) = seq.next_element()? {
+                    jump_steps = jump_steps.insert(step);
+                }
+
+                Ok(jump_steps)
+            }
+        }
+
+        deserializer.deserialize_seq(JumpStepsVisitor)
+    }
+}
@@ -0,0 +1,79 @@
+//! The shared implementation of the template engine helpers, which turn named arguments into a list of page items for templates to loop over.
+
+use serde_json::Value;
+
+use crate::{PageItem, PageUrl, PaginatorBuilder, UrlTemplate};
+
+/**
+    Create the page items from these arguments.
+
+    * `current_page`: The current page. Defaults to 1.
+    * `total_pages`: The number of pages. Required.
+    * `max_item_count`: The max number of page items. Defaults to the one of `PaginatorBuilder`.
+    * `url`: A `UrlTemplate` for the `href` of the items.
+    * `base_url` and `page_param`: A `PageUrl` for the `href` of the items, if `url` is not given.
+
+    Each item is an object with the fields `kind`, `page`, `label`, `href`, `is_current`, `is_disabled` and `is_ellipsis`.
+*/
+pub(crate) fn page_items<'a>(arg: impl Fn(&str) -> Option<&'a Value>) -> Result<Value, String> {
+    let number = |name: &str| match arg(name) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => match value.as_u64() {
+            Some(n) => usize::try_from(n).map(Some).map_err(|_| format!("`{}` is too large", name)),
+            None => Err(format!("`{}` should be a non-negative integer", name)),
+        },
+    };
+
+    let string = |name: &str| match arg(name) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => {
+            value.as_str().map(Some).ok_or_else(|| format!("`{}` should be a string", name))
+        },
+    };
+
+    let total_pages = number("total_pages")?.ok_or("`total_pages` is required")?;
+
+    let mut builder =
+        PaginatorBuilder::new(total_pages).current_page(number("current_page")?.unwrap_or(1));
+
+    if let Some(max_item_count) = number("max_item_count")? {
+        builder = builder.max_item_count(max_item_count);
+    }
+
+    let paginator = builder.build_paginator().map_err(|error| error.to_string())?;
+
+    let url_template = string("url")?.map(UrlTemplate::new);
+    let page_url = match string("base_url")? {
+        Some(base_url) => {
+            Some(PageUrl::new(base_url).page_param(string("page_param")?.unwrap_or("page")))
+        },
+        None => None,
+    };
+
+    let href = |item: &PageItem| match (item.page(), url_template, page_url) {
+        (Some(page), Some(url_template), _) => Value::from(url_template.url(page).to_string()),
+        (Some(page), None, Some(page_url)) => Value::from(page_url.url(page).to_string()),
+        _ => Value::Null,
+    };
+
+    let items = paginator
+        .page_items()
+        .map(|item| {
+            let fields = [
+                ("kind", Value::from(item.kind())),
+                ("page", Value::from(item.page().map(|page| page.get()))),
+                ("label", Value::from(item.label().to_string())),
+                ("href", href(&item)),
+                ("is_current", Value::from(item.is_current())),
+                ("is_disabled", Value::from(item.is_disabled())),
+                ("is_ellipsis", Value::from(item.is_ellipsis())),
+            ];
+
+            Value::Object(
+                fields.into_iter().map(|(name, value)| (name.to_string(), value)).collect(),
+            )
+        })
+        .collect();
+
+    Ok(Value::Array(items))
+}
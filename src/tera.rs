@@ -0,0 +1,41 @@
+/*!
+A [Tera](https://docs.rs/tera) function which creates the page items of a pagination bar, so templates can loop over them without matching `PageItem` variants.
+
+The function takes the named arguments `current_page` (defaults to 1), `total_pages`, `max_item_count` (optional), and either `url` (a `UrlTemplate` such as `"/items?page={page}"`) or `base_url` with an optional `page_param` (a `PageUrl`). Each item is an object with the fields `kind` (see `PageItem::kind`), `page`, `label` (see `PageItem::label`), `href`, `is_current`, `is_disabled` and `is_ellipsis`. The `page` and `href` fields are `null` if the item does not link to a page.
+
+```rust
+use paginator::tera::PageItemsFunction;
+use tera::{Context, Tera};
+
+let mut tera = Tera::default();
+
+tera.register_function("page_items", PageItemsFunction);
+tera.add_raw_template(
+    "pagination",
+    r#"{% for item in page_items(current_page=2, total_pages=3, base_url="/items") %}{% if item.href and not item.is_current %}<a href="{{ item.href }}">{{ item.label }}</a>{% else %}<span>{{ item.label }}</span>{% endif %}{% endfor %}"#,
+)
+.unwrap();
+
+assert_eq!(
+    r#"<a href="/items?page=1">«</a><a href="/items?page=1">1</a><span>2</span><a href="/items?page=3">3</a><a href="/items?page=3">»</a>"#,
+    tera.render("pagination", &Context::new()).unwrap()
+);
+```
+*/
+
+use std::collections::HashMap;
+
+use ::tera::{Error, Function, Result, Value};
+
+use crate::template::page_items;
+
+/// The Tera function which creates page items. See the module documentation.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PageItemsFunction;
+
+impl Function for PageItemsFunction {
+    #[inline]
+    fn call(&self, args: &HashMap<String, Value>) -> Result<Value> {
+        page_items(|name| args.get(name)).map_err(Error::msg)
+    }
+}
@@ -1,5 +1,7 @@
 /// A setting that can be enabled, disabled, or decided from the page state.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum YesNoDepends {
     /// Always enable the item.
     Yes,
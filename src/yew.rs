@@ -0,0 +1,82 @@
+/*!
+A [Yew](https://docs.rs/yew) component which renders a pagination bar.
+
+```rust
+use paginator::{yew::PaginationBar, Paginator};
+use yew::{function_component, html, Callback, Html};
+
+#[function_component]
+fn Items() -> Html {
+    let paginator = Paginator::builder(3).current_page(2).build_paginator().unwrap();
+    let on_change = Callback::from(|page: usize| println!("go to page {}", page));
+
+    html! { <PaginationBar {paginator} {on_change} /> }
+}
+```
+*/
+
+use ::yew::{function_component, html, Callback, Html, KeyboardEvent, Properties};
+
+use crate::{
+    component::{aria_label, click_target, is_button, page_for_key, NAV_ARIA},
+    Paginator,
+};
+
+/// The properties of `PaginationBar`.
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct PaginationBarProps {
+    /// The paginator to render.
+    pub paginator: Paginator,
+    /// Called with the page number to change to.
+    pub on_change: Callback<usize>,
+}
+
+/// Render the items of a paginator as buttons, calling `on_change` with the page number when a page is chosen by a click or a key.
+#[function_component(PaginationBar)]
+pub fn pagination_bar(props: &PaginationBarProps) -> Html {
+    let onkeydown = {
+        let paginator = props.paginator.clone();
+        let on_change = props.on_change.clone();
+
+        Callback::from(move |event: KeyboardEvent| {
+            if let Some(page) = page_for_key(&paginator, &event.key()) {
+                event.prevent_default();
+                on_change.emit(page);
+            }
+        })
+    };
+
+    let items = props.paginator.page_items().map(|item| {
+        let label = item.label().to_string();
+
+        if is_button(&item) {
+            let onclick = click_target(&item).map(|page| props.on_change.reform(move |_| page));
+
+            html! {
+                <li class={item.kind()}>
+                    <button
+                        type="button"
+                        aria-label={aria_label(&item)}
+                        aria-current={if item.is_current() { Some("page") } else { None }}
+                        disabled={item.is_disabled()}
+                        {onclick}
+                    >
+                        {label}
+                    </button>
+                </li>
+            }
+        } else {
+            html! {
+                <li class={item.kind()}>
+                    <span aria-hidden="true">{label}</span>
+                </li>
+            }
+        }
+    });
+
+    html! {
+        <nav aria-label={NAV_ARIA} {onkeydown}>
+            <ul class="pagination">{ for items }</ul>
+        </nav>
+    }
+}
@@ -1,4 +1,4 @@
-use paginator::{PaginatorBuildError, PaginatorBuilder, YesNoDepends};
+use paginator::{Layout, PaginatorBuildError, PaginatorBuilder, YesNoDepends};
 
 #[test]
 fn basic() {
@@ -101,3 +101,112 @@ fn max_item_count_3() {
     assert!(builder.clone().total_pages(8).max_item_count(7).build_paginator().is_ok());
     assert!(builder.total_pages(8).max_item_count(6).build_paginator().is_err());
 }
+
+#[test]
+fn from_items() {
+    assert_eq!(10, PaginatorBuilder::from_items(95, 10).total_pages);
+    assert_eq!(10, PaginatorBuilder::from_items(100, 10).total_pages);
+    assert_eq!(11, PaginatorBuilder::from_items(101, 10).total_pages);
+    assert_eq!(usize::MAX, PaginatorBuilder::from_items(usize::MAX, 1).total_pages);
+
+    let empty = PaginatorBuilder::from_items(0, 10).build_paginator().unwrap();
+
+    assert_eq!(1, empty.total_pages());
+    assert_eq!(Some(0), empty.total_items());
+    assert_eq!(Some(10), empty.per_page());
+
+    let paginator =
+        PaginatorBuilder::from_items(95, 10).current_page(10).build_paginator().unwrap();
+
+    assert_eq!(Some(95), paginator.total_items());
+    assert_eq!(Some(10), paginator.iter().next().unwrap().per_page());
+}
+
+#[test]
+fn invalid_items() {
+    assert_eq!(
+        Err(PaginatorBuildError::PerPageZero),
+        PaginatorBuilder::from_items(10, 0).build_paginator()
+    );
+    assert_eq!(
+        Err(PaginatorBuildError::PerPageZero),
+        PaginatorBuilder::new(3).per_page(0).build_paginator_iter()
+    );
+    assert_eq!(
+        Err(PaginatorBuildError::TotalPagesMismatch {
+            total_pages:          3,
+            expected_total_pages: 10,
+        }),
+        PaginatorBuilder::from_items(95, 10).total_pages(3).build_paginator()
+    );
+    assert!(PaginatorBuilder::new(3).per_page(10).build_paginator().is_ok());
+}
+
+#[test]
+fn first_and_last_budget() {
+    let builder =
+        PaginatorBuilder::new(20).has_first(YesNoDepends::Yes).has_last(YesNoDepends::Yes);
+
+    assert_eq!(
+        Err(PaginatorBuildError::MaxItemCountTooSmall {
+            min_item_count: 9
+        }),
+        builder.clone().max_item_count(8).build_paginator()
+    );
+    assert!(builder.clone().max_item_count(9).build_paginator().is_ok());
+    assert_eq!(
+        Err(PaginatorBuildError::MaxItemCountTooSmall {
+            min_item_count: 4
+        }),
+        builder.total_pages(2).max_item_count(3).build_paginator()
+    );
+}
+
+#[test]
+fn jump_steps() {
+    assert_eq!(
+        &[10, 100],
+        PaginatorBuilder::new(1).jump_steps(&[100, 10, 0, 10]).jump_steps.as_slice()
+    );
+    assert_eq!(
+        &[1, 2, 3, 4],
+        PaginatorBuilder::new(1).jump_steps(&[4, 3, 2, 1, 5]).jump_steps.as_slice()
+    );
+
+    let builder = PaginatorBuilder::new(200).jump_steps(&[10, 100, 1000]);
+
+    assert_eq!(
+        Err(PaginatorBuildError::MaxItemCountTooSmall {
+            min_item_count: 11
+        }),
+        builder.clone().max_item_count(10).build_paginator()
+    );
+    assert!(builder.max_item_count(11).build_paginator().is_ok());
+}
+
+#[test]
+fn logarithmic_budget() {
+    let builder = PaginatorBuilder::new(200).layout(Layout::Logarithmic);
+
+    assert_eq!(
+        Err(PaginatorBuildError::MaxItemCountTooSmall {
+            min_item_count: 7
+        }),
+        builder.clone().max_item_count(6).build_paginator()
+    );
+    assert!(builder.max_item_count(7).build_paginator().is_ok());
+}
+
+#[test]
+fn unknown_total_budget() {
+    let builder = PaginatorBuilder::unknown_total(100, true);
+
+    // The first page, an ignore marker, the current page, the next page, a more marker, and the prev and next items.
+    assert_eq!(
+        Err(PaginatorBuildError::MaxItemCountTooSmall {
+            min_item_count: 7
+        }),
+        builder.clone().max_item_count(6).build_paginator()
+    );
+    assert!(builder.max_item_count(7).build_paginator().is_ok());
+}
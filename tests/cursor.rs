@@ -0,0 +1,73 @@
+#[cfg(feature = "alloc")]
+use paginator::{decode_cursor, encode_cursor, CursorTokenError};
+use paginator::{CursorItem, CursorPaginator, YesNoDepends};
+
+#[test]
+fn items() {
+    let paginator = CursorPaginator::new().before(1).after(2);
+
+    assert_eq!(
+        vec![CursorItem::Prev(&1), CursorItem::Next(&2)],
+        paginator.items().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![CursorItem::Next(&2), CursorItem::Prev(&1)],
+        paginator.items().rev().collect::<Vec<_>>()
+    );
+    assert_eq!(2, paginator.items().len());
+
+    let first_page = CursorPaginator::new().after("b");
+
+    assert_eq!(vec![CursorItem::Next(&"b")], first_page.items().collect::<Vec<_>>());
+    assert_eq!(
+        vec![CursorItem::ReservedPrev, CursorItem::Next(&"b")],
+        first_page.clone().has_prev(YesNoDepends::Yes).items().collect::<Vec<_>>()
+    );
+    assert_eq!(0, first_page.has_next(YesNoDepends::No).items().count());
+
+    let empty =
+        CursorPaginator::<u64>::new().has_prev(YesNoDepends::Yes).has_next(YesNoDepends::Yes);
+
+    assert_eq!(
+        vec![CursorItem::ReservedPrev, CursorItem::ReservedNext],
+        empty.items().collect::<Vec<_>>()
+    );
+    assert_eq!(") (", empty.items().map(|item| item.to_string()).collect::<Vec<_>>().join(" "));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn tokens() {
+    for (bytes, token) in [
+        (&b""[..], ""),
+        (b"f", "Zg"),
+        (b"fo", "Zm8"),
+        (b"foo", "Zm9v"),
+        (b"foob", "Zm9vYg"),
+        (b"\xfb\xff\xbf", "-_-_"),
+    ] {
+        assert_eq!(token, encode_cursor(bytes));
+        assert_eq!(Ok(bytes.to_vec()), decode_cursor(token));
+    }
+
+    let bytes: Vec<u8> = (0..=255).collect();
+
+    assert_eq!(Ok(bytes.clone()), decode_cursor(&encode_cursor(&bytes)));
+
+    assert_eq!(Ok(b"fo".to_vec()), decode_cursor("Zm8="));
+    assert_eq!(Ok(b"f".to_vec()), decode_cursor("Zg=="));
+
+    assert_eq!(Err(CursorTokenError::InvalidLength), decode_cursor("Zm9vY"));
+    assert_eq!(
+        Err(CursorTokenError::InvalidCharacter {
+            index: 3
+        }),
+        decode_cursor("Zm9/")
+    );
+    assert_eq!(
+        Err(CursorTokenError::InvalidCharacter {
+            index: 1
+        }),
+        decode_cursor("Zh")
+    );
+}
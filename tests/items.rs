@@ -0,0 +1,77 @@
+use paginator::PaginatorBuilder;
+
+#[test]
+fn offset_and_limit() {
+    let mut iter = PaginatorBuilder::from_items(95, 10).build_paginator_iter().unwrap();
+
+    let first = iter.next().unwrap();
+
+    assert_eq!(Some(0), first.offset());
+    assert_eq!(Some(10), first.limit());
+    assert_eq!(Some(0..10), first.item_range());
+
+    let second = iter.next().unwrap();
+
+    assert_eq!(Some(10), second.offset());
+    assert_eq!(Some(10..20), second.item_range());
+
+    let last = iter.last().unwrap();
+
+    assert_eq!(Some(90), last.offset());
+    assert_eq!(Some(90..95), last.item_range());
+}
+
+#[test]
+fn unknown_page_size() {
+    let paginator = PaginatorBuilder::new(5).current_page(3).build_paginator().unwrap();
+
+    assert_eq!(None, paginator.offset());
+    assert_eq!(None, paginator.limit());
+    assert_eq!(None, paginator.item_range());
+    assert_eq!(None, paginator.page_of_item(0));
+
+    let paginator =
+        PaginatorBuilder::new(5).current_page(3).per_page(20).build_paginator().unwrap();
+
+    assert_eq!(Some(40..60), paginator.item_range());
+    assert_eq!(Some(5), paginator.page_of_item(99));
+    assert_eq!(None, paginator.page_of_item(100));
+}
+
+#[test]
+fn empty_items() {
+    let paginator = PaginatorBuilder::from_items(0, 10).build_paginator().unwrap();
+
+    assert_eq!(Some(0), paginator.offset());
+    assert_eq!(Some(0..0), paginator.item_range());
+    assert_eq!(None, paginator.page_of_item(0));
+}
+
+#[test]
+fn page_of_item() {
+    let paginator = PaginatorBuilder::from_items(95, 10).build_paginator().unwrap();
+
+    assert_eq!(Some(1), paginator.page_of_item(0));
+    assert_eq!(Some(1), paginator.page_of_item(9));
+    assert_eq!(Some(2), paginator.page_of_item(10));
+    assert_eq!(Some(10), paginator.page_of_item(94));
+    assert_eq!(None, paginator.page_of_item(95));
+}
+
+#[test]
+fn large_offsets_do_not_overflow() {
+    let paginator = PaginatorBuilder::new(usize::MAX)
+        .current_page(usize::MAX)
+        .per_page(2)
+        .build_paginator()
+        .unwrap();
+
+    assert_eq!(None, paginator.offset());
+    assert_eq!(None, paginator.item_range());
+
+    let paginator =
+        PaginatorBuilder::from_items(usize::MAX, 2).current_page(2).build_paginator().unwrap();
+
+    assert_eq!(Some(2..4), paginator.item_range());
+    assert_eq!(Some(isize::MAX as usize + 1), paginator.page_of_item(usize::MAX - 1));
+}
@@ -0,0 +1,259 @@
+use std::num::NonZeroUsize;
+
+use paginator::{page_items_to_string, Layout, LayoutError, LayoutStrategy, PageItem, Paginator};
+
+struct Emit(Vec<PageItem>);
+
+impl LayoutStrategy for Emit {
+    fn layout(
+        &self,
+        _paginator: &Paginator,
+        _max_item_count: usize,
+        emit: &mut dyn FnMut(PageItem),
+    ) {
+        self.0.iter().copied().for_each(emit);
+    }
+}
+
+/// Keep the current page at the third shown page, like a fixed-position bar, and hide the pages around them.
+struct FixedPosition;
+
+impl LayoutStrategy for FixedPosition {
+    fn layout(&self, paginator: &Paginator, max_item_count: usize, emit: &mut dyn FnMut(PageItem)) {
+        let current_page = paginator.current_page();
+        let total_pages = paginator.total_pages();
+        let first_page = current_page.saturating_sub(2).max(1);
+
+        let mut slot_count = max_item_count;
+
+        if first_page > 1 {
+            emit(PageItem::Ignore {
+                start: page(1), end: page(first_page - 1)
+            });
+
+            slot_count -= 1;
+        }
+
+        // Keep a slot for the hidden pages after the shown ones.
+        let last_page = if total_pages - first_page < slot_count {
+            total_pages
+        } else {
+            first_page + slot_count - 2
+        };
+
+        for page in first_page..=last_page {
+            let page_number = NonZeroUsize::new(page).unwrap();
+
+            if page == current_page {
+                emit(PageItem::CurrentPage(page_number));
+            } else {
+                emit(PageItem::Page(page_number));
+            }
+        }
+
+        if last_page < total_pages {
+            emit(PageItem::Ignore {
+                start: page(last_page + 1), end: page(total_pages)
+            });
+        }
+    }
+}
+
+fn page(page: usize) -> NonZeroUsize {
+    NonZeroUsize::new(page).unwrap()
+}
+
+#[test]
+fn built_in_layouts() {
+    for layout in [Layout::Window, Layout::Logarithmic] {
+        for total_pages in 1..=40 {
+            let iter = Paginator::builder(total_pages)
+                .max_item_count(11)
+                .has_first(paginator::YesNoDepends::Depends)
+                .jump_steps(&[10])
+                .layout(layout)
+                .build_paginator_iter()
+                .unwrap();
+
+            for paginator in iter {
+                assert_eq!(paginator.paginate(), paginator.paginate_with(&layout).unwrap());
+            }
+        }
+    }
+}
+
+#[test]
+fn custom_strategy() {
+    let builder = Paginator::builder(100).max_item_count(7);
+
+    assert_eq!(
+        "< ... 48 49 50* ... >",
+        page_items_to_string(
+            &builder
+                .clone()
+                .current_page(50)
+                .build_paginator()
+                .unwrap()
+                .paginate_with(&FixedPosition)
+                .unwrap()
+        )
+    );
+    assert_eq!(
+        "1* 2 3 4 5 ... >",
+        page_items_to_string(
+            &builder
+                .current_page(1)
+                .build_paginator()
+                .unwrap()
+                .paginate_with(&FixedPosition)
+                .unwrap()
+        )
+    );
+
+    let mut items = Vec::new();
+
+    Paginator::builder(100)
+        .current_page(50)
+        .max_item_count(7)
+        .build_paginator()
+        .unwrap()
+        .paginate_with_into(&FixedPosition, &mut items)
+        .unwrap();
+
+    assert_eq!(7, items.len());
+}
+
+#[test]
+fn invalid_output() {
+    let paginator =
+        Paginator::builder(10).current_page(5).max_item_count(7).build_paginator().unwrap();
+
+    assert_eq!(
+        Err(LayoutError::TooManyItems {
+            max_item_count: 5
+        }),
+        paginator.paginate_with(&Emit(vec![
+            PageItem::Page(page(1)),
+            PageItem::Page(page(2)),
+            PageItem::Page(page(3)),
+            PageItem::Page(page(4)),
+            PageItem::CurrentPage(page(5)),
+            PageItem::Page(page(6)),
+        ]))
+    );
+    assert_eq!(
+        Err(LayoutError::PageOutOfRange {
+            page: 11, total_pages: 10
+        }),
+        paginator.paginate_with(&Emit(vec![
+            PageItem::Ignore {
+                start: page(1), end: page(4)
+            },
+            PageItem::CurrentPage(page(5)),
+            PageItem::Ignore {
+                start: page(6), end: page(11)
+            },
+        ]))
+    );
+    assert_eq!(
+        Err(LayoutError::PagesNotAscending {
+            page: 4
+        }),
+        paginator.paginate_with(&Emit(vec![
+            PageItem::Ignore {
+                start: page(1), end: page(4)
+            },
+            PageItem::CurrentPage(page(5)),
+            PageItem::Page(page(4)),
+        ]))
+    );
+    assert_eq!(
+        Err(LayoutError::UnexpectedItem(PageItem::Page(page(5)))),
+        paginator.paginate_with(&Emit(vec![PageItem::Page(page(5))]))
+    );
+    assert_eq!(
+        Err(LayoutError::UnexpectedItem(PageItem::Next(page(6)))),
+        paginator.paginate_with(&Emit(vec![
+            PageItem::Ignore {
+                start: page(1), end: page(4)
+            },
+            PageItem::CurrentPage(page(5)),
+            PageItem::Next(page(6)),
+        ]))
+    );
+    assert_eq!(
+        Err(LayoutError::MissingCurrentPage),
+        paginator.paginate_with(&Emit(vec![PageItem::Ignore {
+            start: page(1), end: page(4)
+        }]))
+    );
+}
+
+#[test]
+fn missing_pages() {
+    let paginator =
+        Paginator::builder(10).current_page(5).max_item_count(9).build_paginator().unwrap();
+
+    // the pages before the first item
+    assert_eq!(
+        Err(LayoutError::MissingPages {
+            start: 1, end: 4
+        }),
+        paginator.paginate_with(&Emit(vec![PageItem::CurrentPage(page(5))]))
+    );
+
+    // a gap between two pages
+    assert_eq!(
+        Err(LayoutError::MissingPages {
+            start: 2, end: 4
+        }),
+        paginator
+            .paginate_with(&Emit(vec![PageItem::Page(page(1)), PageItem::CurrentPage(page(5))]))
+    );
+
+    // a gap before an ignore item
+    assert_eq!(
+        Err(LayoutError::MissingPages {
+            start: 2, end: 2
+        }),
+        paginator.paginate_with(&Emit(vec![
+            PageItem::Page(page(1)),
+            PageItem::Ignore {
+                start: page(3), end: page(4)
+            },
+            PageItem::CurrentPage(page(5)),
+        ]))
+    );
+
+    // a trailing ignore item which does not reach the last page
+    assert_eq!(
+        Err(LayoutError::MissingPages {
+            start: 9, end: 10
+        }),
+        paginator.paginate_with(&Emit(vec![
+            PageItem::Ignore {
+                start: page(1), end: page(4)
+            },
+            PageItem::CurrentPage(page(5)),
+            PageItem::Ignore {
+                start: page(6), end: page(8)
+            },
+        ]))
+    );
+
+    // a bar may stop at a shown page
+    assert_eq!(
+        "< 1 2 3 4 5* >",
+        page_items_to_string(
+            &paginator
+                .paginate_with(&Emit(vec![
+                    PageItem::Page(page(1)),
+                    PageItem::Page(page(2)),
+                    PageItem::Page(page(3)),
+                    PageItem::Page(page(4)),
+                    PageItem::CurrentPage(page(5)),
+                ]))
+                .unwrap()
+        )
+    );
+}
@@ -0,0 +1,107 @@
+use std::num::NonZeroUsize;
+
+use paginator::{
+    parse_link_header, LinkHeaderError, LinkHeaderPages, Paginator, PaginatorBuilder, UrlTemplate,
+};
+
+fn page(page: usize) -> Option<NonZeroUsize> {
+    NonZeroUsize::new(page)
+}
+
+#[test]
+fn format() {
+    let template = UrlTemplate::new("/items?page={page}&per_page=10");
+
+    assert_eq!(
+        r#"</items?page=1&per_page=10>; rel="first", </items?page=2&per_page=10>; rel="next", </items?page=3&per_page=10>; rel="last""#,
+        Paginator::builder(3)
+            .build_paginator()
+            .unwrap()
+            .links_with_template(template)
+            .link_header()
+            .to_string()
+    );
+    assert_eq!(
+        r#"</items?page=1&per_page=10>; rel="first", </items?page=3&per_page=10>; rel="prev", </items?page=5&per_page=10>; rel="next""#,
+        PaginatorBuilder::unknown_total(4, true)
+            .build_paginator()
+            .unwrap()
+            .links_with_template(template)
+            .link_header()
+            .to_string()
+    );
+    assert_eq!(
+        "/a/7/b/7",
+        UrlTemplate::new("/a/{page}/b/{page}").url(page(7).unwrap()).to_string()
+    );
+}
+
+#[test]
+fn round_trip() {
+    let template = UrlTemplate::new("https://api.example.com/items?sort=name&page={page}");
+
+    for paginator in Paginator::builder(4).build_paginator_iter().unwrap() {
+        let header = paginator.links_with_template(template).link_header().to_string();
+        let links = paginator.links(|page| page);
+
+        assert_eq!(
+            LinkHeaderPages {
+                first:   Some(links.first),
+                prev:    links.prev,
+                current: None,
+                next:    links.next,
+                last:    links.last,
+            },
+            parse_link_header(&header, "page").unwrap()
+        );
+    }
+}
+
+#[test]
+fn parse() {
+    assert_eq!(Ok(LinkHeaderPages::default()), parse_link_header("", "page"));
+
+    let pages = parse_link_header(
+        "<https://example.com/?q=a,b;c&p=2#top>;rel=\"next last\" ; title=\"a, \\\"b\\\"; c\", \
+         <https://example.com/>; REL=first,, <https://example.com/?p=1>; rel=previous; \
+         rel=\"next\", <https://example.com/?p=9>; rel=\"stylesheet\"",
+        "p",
+    )
+    .unwrap();
+
+    assert_eq!(
+        LinkHeaderPages {
+            first:   page(1),
+            prev:    page(1),
+            current: None,
+            next:    page(2),
+            last:    page(2),
+        },
+        pages
+    );
+
+    assert_eq!(
+        Err(LinkHeaderError::InvalidSyntax {
+            index: 0
+        }),
+        parse_link_header("https://example.com/", "page")
+    );
+    assert_eq!(
+        Err(LinkHeaderError::InvalidSyntax {
+            index: 16
+        }),
+        parse_link_header("</?page=2>; rel=\"next", "page")
+    );
+    assert_eq!(
+        Err(LinkHeaderError::InvalidSyntax {
+            index: 11
+        }),
+        parse_link_header("</?page=2> rel=next", "page")
+    );
+    assert_eq!(
+        Err(LinkHeaderError::InvalidPage {
+            index: 22
+        }),
+        parse_link_header("</?page=1>; rel=prev, </?page=0>; rel=next", "page")
+    );
+}
@@ -0,0 +1,116 @@
+use core::iter::FusedIterator;
+
+#[cfg(feature = "alloc")]
+use paginator::YesNoDepends;
+use paginator::{Layout, PageItem, PageItems, Paginator};
+
+fn assert_iterator_traits<T: ExactSizeIterator + DoubleEndedIterator + FusedIterator>() {}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn same_as_paginate() {
+    for total_pages in 1..=30 {
+        for max_item_count in 7..=12 {
+            for has_prev_next in [YesNoDepends::Yes, YesNoDepends::No, YesNoDepends::Depends] {
+                let iter = Paginator::builder(total_pages)
+                    .max_item_count(max_item_count)
+                    .has_prev(has_prev_next)
+                    .has_next(has_prev_next)
+                    .build_paginator_iter()
+                    .unwrap();
+
+                for paginator in iter {
+                    let items = paginator.paginate();
+
+                    assert_eq!(items.len(), paginator.page_items().len());
+                    assert!(items.iter().copied().eq(paginator.page_items()));
+                    assert!(items.iter().rev().copied().eq(paginator.page_items().rev()));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn double_ended() {
+    assert_iterator_traits::<PageItems>();
+
+    let paginator = Paginator::builder(20).current_page(10).build_paginator().unwrap();
+
+    let mut iter = paginator.page_items();
+
+    assert_eq!(9, iter.len());
+    assert_eq!(Some(PageItem::Prev(9.try_into().unwrap())), iter.next());
+    assert_eq!(Some(PageItem::Next(11.try_into().unwrap())), iter.next_back());
+    assert_eq!(Some(PageItem::Page(1.try_into().unwrap())), iter.next());
+    assert_eq!(Some(PageItem::Page(20.try_into().unwrap())), iter.next_back());
+    assert_eq!(5, iter.len());
+    assert_eq!(Some(PageItem::CurrentPage(10.try_into().unwrap())), iter.nth(2));
+    assert_eq!(
+        Some(PageItem::Ignore {
+            start: 12.try_into().unwrap(), end: 19.try_into().unwrap()
+        }),
+        iter.nth_back(0)
+    );
+    assert_eq!(Some(PageItem::Page(11.try_into().unwrap())), iter.next());
+    assert_eq!(None, iter.next());
+    assert_eq!(None, iter.next_back());
+}
+
+#[test]
+fn large_page_numbers_do_not_overflow() {
+    let paginator =
+        Paginator::builder(usize::MAX).current_page(usize::MAX).build_paginator().unwrap();
+
+    let mut iter = paginator.page_items();
+
+    assert_eq!(9, iter.len());
+    assert_eq!(Some(PageItem::CurrentPage(usize::MAX.try_into().unwrap())), iter.next_back());
+    assert_eq!(None, iter.nth(usize::MAX));
+}
+
+#[test]
+fn large_page_numbers_with_more_do_not_overflow() {
+    for layout in [Layout::Window, Layout::Logarithmic] {
+        let paginator = Paginator::builder(usize::MAX - 1)
+            .has_more(true)
+            .fixed_width(true)
+            .layout(layout)
+            .build_paginator()
+            .unwrap();
+
+        let items = paginator.page_items().collect::<Vec<_>>();
+
+        assert_eq!(paginator.max_item_count(), items.len());
+        assert!(!items.iter().any(|item| matches!(item, PageItem::More { .. })));
+    }
+}
+
+#[test]
+fn paginate_into() {
+    let mut buffer = Vec::new();
+
+    for paginator in Paginator::builder(20).build_paginator_iter().unwrap() {
+        buffer.clear();
+
+        paginator.paginate_into(&mut buffer);
+
+        assert_eq!(paginator.page_items().collect::<Vec<_>>(), buffer);
+    }
+}
+
+#[test]
+fn paginate_array() {
+    for paginator in Paginator::builder(20).max_item_count(11).build_paginator_iter().unwrap() {
+        let items = paginator.paginate_array::<11>().unwrap();
+
+        assert_eq!(11, items.capacity());
+        assert!(paginator.page_items().eq(items.iter().copied()));
+        assert_eq!(paginator.page_items().len(), items.iter().count());
+    }
+
+    let paginator = Paginator::builder(3).max_item_count(11).build_paginator().unwrap();
+
+    assert!(paginator.paginate_array::<10>().is_none());
+    assert_eq!(4, paginator.paginate_array::<11>().unwrap().len());
+}
@@ -0,0 +1,67 @@
+use paginator::{PageLinks, Paginator, PaginatorBuilder};
+
+#[test]
+fn links() {
+    let links = Paginator::builder(3).build_paginator().unwrap().links(|page| page.get());
+
+    assert_eq!(
+        PageLinks {
+            first: 1, prev: None, current: 1, next: Some(2), last: Some(3)
+        },
+        links
+    );
+    assert_eq!(
+        vec![("first", &1), ("self", &1), ("next", &2), ("last", &3)],
+        links.iter().collect::<Vec<_>>()
+    );
+
+    let links =
+        Paginator::builder(3).current_page(3).build_paginator().unwrap().links(|page| page.get());
+
+    assert_eq!(Some(2), links.prev);
+    assert_eq!(None, links.next);
+
+    // There is no last page if the number of pages is unknown.
+    let links = PaginatorBuilder::unknown_total(4, true)
+        .build_paginator()
+        .unwrap()
+        .links(|page| page.get());
+
+    assert_eq!(Some(5), links.next);
+    assert_eq!(None, links.last);
+}
+
+#[test]
+fn json() {
+    let paginator = Paginator::builder(1).build_paginator().unwrap();
+
+    assert_eq!(
+        r#"{"first":"/?page=1","prev":null,"self":"/?page=1","next":null,"last":"/?page=1"}"#,
+        paginator.links_with_base_url("/", "page").json_api().to_string()
+    );
+    assert_eq!(
+        r#"{"first":{"href":"/?page=1"},"self":{"href":"/?page=1"},"last":{"href":"/?page=1"}}"#,
+        paginator.links_with_base_url("/", "page").hal().to_string()
+    );
+
+    let links = paginator.links(|page| format!("/search?q=\"a\\b\"\n&p={}", page));
+
+    assert_eq!(
+        r#"{"first":{"href":"/search?q=\"a\\b\"\u000a&p=1"},"self":{"href":"/search?q=\"a\\b\"\u000a&p=1"},"last":{"href":"/search?q=\"a\\b\"\u000a&p=1"}}"#,
+        links.hal().to_string()
+    );
+}
+
+#[test]
+fn meta() {
+    assert_eq!(
+        r#"{"current_page":2,"total_pages":5}"#,
+        Paginator::builder(5).current_page(2).build_paginator().unwrap().meta().to_string()
+    );
+
+    let meta =
+        PaginatorBuilder::unknown_total(4, true).per_page(20).build_paginator().unwrap().meta();
+
+    assert_eq!(None, meta.total_pages());
+    assert_eq!(r#"{"current_page":4,"per_page":20}"#, meta.to_string());
+}
@@ -0,0 +1,114 @@
+use paginator::{PageParams, PageQuery, PageQueryError};
+
+#[test]
+fn strict() {
+    let query = PageQuery::new().default_per_page(10).max_per_page(50);
+
+    assert_eq!(
+        Ok(PageParams {
+            page: 1, per_page: 10
+        }),
+        query.parse("")
+    );
+    assert_eq!(
+        Ok(PageParams {
+            page: 1, per_page: 10
+        }),
+        query.parse("?page=&per_page=")
+    );
+    assert_eq!(
+        Ok(PageParams {
+            page: 3, per_page: 25
+        }),
+        query.parse("q=a&per_page=25&page=3&page=9")
+    );
+    assert_eq!(
+        Ok(PageParams {
+            page: 2, per_page: 10
+        }),
+        query.parse("?sort=name&pa%67e=2#top")
+    );
+
+    assert_eq!(Err(PageQueryError::InvalidPage), query.parse("page=0"));
+    assert_eq!(Err(PageQueryError::InvalidPage), query.parse("page=-1"));
+    assert_eq!(Err(PageQueryError::InvalidPage), query.parse("page=2.5"));
+    assert_eq!(Err(PageQueryError::InvalidPerPage), query.parse("per_page=0"));
+    assert_eq!(Err(PageQueryError::InvalidPerPage), query.parse("per_page=ten"));
+    assert_eq!(
+        Err(PageQueryError::PerPageTooLarge {
+            per_page: 51, max_per_page: 50
+        }),
+        query.parse("per_page=51")
+    );
+    assert_eq!(
+        Err(PageQueryError::PerPageTooLarge {
+            per_page: usize::MAX, max_per_page: 50
+        }),
+        query.parse("per_page=99999999999999999999999")
+    );
+
+    let builder = query.builder("page=10&per_page=10", 95).unwrap();
+
+    assert_eq!(10, builder.total_pages);
+    assert_eq!(10, builder.current_page);
+    assert_eq!(
+        Err(PageQueryError::PageTooLarge {
+            page: 11, total_pages: 10
+        }),
+        query.builder("page=11&per_page=10", 95)
+    );
+}
+
+#[test]
+fn lenient() {
+    let query =
+        PageQuery::new().page_param("p").per_page_param("size").max_per_page(50).lenient(true);
+
+    assert_eq!(
+        Ok(PageParams {
+            page: 1, per_page: 20
+        }),
+        query.parse("page=5&p=abc&size=x")
+    );
+    assert_eq!(
+        Ok(PageParams {
+            page: 1, per_page: 1
+        }),
+        query.parse("p=0&size=0")
+    );
+    assert_eq!(
+        Ok(PageParams {
+            page: 4, per_page: 50
+        }),
+        query.parse("p=4&size=51")
+    );
+
+    let paginator =
+        query.builder("p=99999999999999999999&size=10", 95).unwrap().build_paginator().unwrap();
+
+    assert_eq!(10, paginator.current_page());
+    assert_eq!(Some(95), paginator.total_items());
+
+    let paginator = query.builder("p=3", 0).unwrap().build_paginator().unwrap();
+
+    assert_eq!(1, paginator.current_page());
+    assert_eq!(1, paginator.total_pages());
+}
+
+#[test]
+fn offset() {
+    assert_eq!(
+        0,
+        PageParams {
+            page: 1, per_page: 20
+        }
+        .offset()
+    );
+    assert_eq!(
+        40,
+        PageParams {
+            page: 3, per_page: 20
+        }
+        .offset()
+    );
+}
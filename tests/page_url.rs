@@ -0,0 +1,74 @@
+use std::num::NonZeroUsize;
+
+use paginator::{PageItem, PageUrl, Paginator};
+
+fn page(page: usize) -> NonZeroUsize {
+    NonZeroUsize::new(page).unwrap()
+}
+
+#[test]
+fn query_string() {
+    let page_url = PageUrl::new("/items");
+
+    assert_eq!("/items?page=1", page_url.url(page(1)).to_string());
+    assert_eq!("/items?page=2", PageUrl::new("/items?").url(page(2)).to_string());
+
+    let page_url = PageUrl::new("/items?sort=name&&page=7&filter=a%20b&Page=3").page_param("page");
+
+    assert_eq!("/items?sort=name&filter=a%20b&Page=3&page=2", page_url.url(page(2)).to_string());
+
+    // An encoded parameter name is replaced as well.
+    let page_url = PageUrl::new("/items?pa%67e=7&per+page=50&x=1").per_page("per page", 20);
+
+    assert_eq!("/items?x=1&page=4&per%20page=20", page_url.url(page(4)).to_string());
+
+    let page_url = PageUrl::new("https://example.com/a b?q=ü&page=2#top").page_param("p&q");
+
+    assert_eq!(
+        "https://example.com/a%20b?q=%C3%BC&page=2&p%26q=5#top",
+        page_url.url(page(5)).to_string()
+    );
+}
+
+#[test]
+fn omit_first_page() {
+    let page_url = PageUrl::new("/items?page=3").omit_first_page(true);
+
+    assert_eq!("/items", page_url.url(page(1)).to_string());
+    assert_eq!("/items?page=2", page_url.url(page(2)).to_string());
+
+    let page_url = PageUrl::new("/items?sort=name#list").per_page("size", 10).omit_first_page(true);
+
+    assert_eq!("/items?sort=name&size=10#list", page_url.url(page(1)).to_string());
+    assert_eq!("/items?sort=name&page=2&size=10#list", page_url.url(page(2)).to_string());
+}
+
+#[test]
+fn page_items() {
+    let page_url = PageUrl::new("/items").omit_first_page(true);
+
+    let paginator = Paginator::builder(20).current_page(10).build_paginator().unwrap();
+
+    let urls = paginator
+        .page_items()
+        .map(|item| page_url.item_url(&item).map(|url| url.to_string()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            Some("/items?page=9".to_string()),
+            Some("/items".to_string()),
+            None,
+            Some("/items?page=9".to_string()),
+            Some("/items?page=10".to_string()),
+            Some("/items?page=11".to_string()),
+            None,
+            Some("/items?page=20".to_string()),
+            Some("/items?page=11".to_string()),
+        ],
+        urls
+    );
+
+    assert_eq!(None, PageItem::ReservedPrev.page());
+    assert_eq!(Some(page(3)), PageItem::CurrentPage(page(3)).page());
+}
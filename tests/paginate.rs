@@ -1,4 +1,8 @@
-use paginator::{page_items_to_string, PageItem, Paginator, YesNoDepends};
+use std::num::NonZeroUsize;
+
+use paginator::{
+    page_items_to_string, Layout, PageItem, Paginator, PaginatorBuilder, YesNoDepends,
+};
 
 #[test]
 fn one_page() {
@@ -479,3 +483,337 @@ fn twenty_pages() {
         page_items_to_string(p.next().unwrap().paginate().as_slice())
     );
 }
+
+#[test]
+fn first_and_last() {
+    let mut p = Paginator::builder(20)
+        .max_item_count(11)
+        .has_first(YesNoDepends::Yes)
+        .has_last(YesNoDepends::Yes)
+        .build_paginator_iter()
+        .unwrap();
+
+    assert_eq!(
+        ")) 1* 2 3 4 5 6 ... 20 > >>",
+        page_items_to_string(p.next().unwrap().paginate().as_slice())
+    );
+    assert_eq!(
+        "<< < 1 2* 3 4 5 ... 20 > >>",
+        page_items_to_string(p.next().unwrap().paginate().as_slice())
+    );
+    assert_eq!(
+        "<< < 1 ... 9 10* 11 ... 20 > >>",
+        p.nth(7).map(|p| page_items_to_string(&p.paginate())).unwrap()
+    );
+    assert_eq!(
+        "<< < 1 ... 15 16 17 18 19 20* ((",
+        page_items_to_string(&p.last().unwrap().paginate())
+    );
+
+    let mut p = Paginator::builder(3)
+        .has_first(YesNoDepends::Depends)
+        .has_last(YesNoDepends::Depends)
+        .build_paginator_iter()
+        .unwrap();
+
+    assert_eq!("1* 2 3 > >>", page_items_to_string(&p.next().unwrap().paginate()));
+    assert_eq!("<< < 1 2* 3 > >>", page_items_to_string(&p.next().unwrap().paginate()));
+    assert_eq!("<< < 1 2 3*", page_items_to_string(&p.next().unwrap().paginate()));
+}
+
+#[test]
+fn jump_target() {
+    let ignore = |start: usize, end: usize| PageItem::Ignore {
+        start: start.try_into().unwrap(),
+        end:   end.try_into().unwrap(),
+    };
+
+    assert_eq!(Some(7), ignore(5, 9).jump_target().map(NonZeroUsize::get));
+    assert_eq!(Some(5), ignore(5, 5).jump_target().map(NonZeroUsize::get));
+    assert_eq!(
+        Some(usize::MAX - 1),
+        ignore(usize::MAX - 2, usize::MAX).jump_target().map(NonZeroUsize::get)
+    );
+    assert_eq!(None, ignore(9, 5).jump_target());
+}
+
+#[test]
+fn ignore_ranges_cover_hidden_pages() {
+    for total_pages in 1..=40 {
+        for max_item_count in 5..=15 {
+            for start_size in 0..=3 {
+                for end_size in 0..=3 {
+                    let iter = match Paginator::builder(total_pages)
+                        .max_item_count(max_item_count)
+                        .start_size(start_size)
+                        .end_size(end_size)
+                        .build_paginator_iter()
+                    {
+                        Ok(iter) => iter,
+                        Err(_) => continue,
+                    };
+
+                    for paginator in iter {
+                        let mut next_page = 1;
+
+                        for item in paginator.paginate() {
+                            match item {
+                                PageItem::Page(page) | PageItem::CurrentPage(page) => {
+                                    assert_eq!(next_page, page.get());
+
+                                    next_page += 1;
+                                },
+                                PageItem::Ignore {
+                                    start,
+                                    end,
+                                } => {
+                                    assert_eq!(next_page, start.get());
+                                    assert!(start <= end);
+
+                                    next_page = end.get() + 1;
+                                },
+                                _ => (),
+                            }
+                        }
+
+                        assert_eq!(total_pages + 1, next_page);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn jump_steps() {
+    let mut p = Paginator::builder(200)
+        .max_item_count(13)
+        .jump_steps(&[10, 100])
+        .build_paginator_iter()
+        .unwrap();
+
+    assert_eq!(
+        "(-100) (-10) 1* 2 3 4 5 6 ... 200 +10 +100 >",
+        page_items_to_string(&p.next().unwrap().paginate())
+    );
+
+    let items = p.nth(48).unwrap().paginate();
+
+    assert_eq!("< -100 -10 1 ... 49 50* 51 ... 200 +10 +100 >", page_items_to_string(&items));
+    assert_eq!(
+        Some(&PageItem::JumpBackward {
+            page: 1.try_into().unwrap(), step: 100
+        }),
+        items.get(1)
+    );
+    assert_eq!(
+        Some(&PageItem::JumpForward {
+            page: 150.try_into().unwrap(), step: 100
+        }),
+        items.get(11)
+    );
+
+    let last = p.last().unwrap().paginate();
+
+    // The jumps cannot leave the last page, so they are reserved.
+    assert_eq!(
+        "< -100 -10 1 ... 195 196 197 198 199 200* (+10) (+100)",
+        page_items_to_string(&last)
+    );
+    assert_eq!(
+        Some(&PageItem::ReservedJumpForward {
+            step: 10
+        }),
+        last.get(last.len() - 2)
+    );
+
+    // Steps which are not smaller than the total pages are not used.
+    let p = Paginator::builder(50).jump_steps(&[10, 100]).build_paginator().unwrap();
+
+    assert_eq!("(-10) 1* 2 3 4 ... 50 +10 >", page_items_to_string(&p.paginate()));
+}
+
+#[test]
+fn logarithmic() {
+    let builder = Paginator::builder(1000).layout(Layout::Logarithmic);
+
+    assert_eq!(
+        "< 1 ... 50 ... 80 ... 90 ... 95 ... 98 99 100* 101 102 ... 105 ... 110 ... 120 ... 1000 >",
+        page_items_to_string(
+            &builder
+                .clone()
+                .current_page(100)
+                .max_item_count(25)
+                .build_paginator()
+                .unwrap()
+                .paginate()
+        )
+    );
+    assert_eq!(
+        "1* 2 3 ... 6 ... 1000 >",
+        page_items_to_string(
+            &builder.clone().current_page(1).build_paginator().unwrap().paginate()
+        )
+    );
+    assert_eq!(
+        "< 1 ... 995 ... 998 999 1000*",
+        page_items_to_string(
+            &builder.clone().current_page(1000).build_paginator().unwrap().paginate()
+        )
+    );
+    assert_eq!(
+        "< 1 ... 499 500* 501 ... 1000 >",
+        page_items_to_string(&builder.current_page(500).build_paginator().unwrap().paginate())
+    );
+
+    // Few pages fall back to showing all of them.
+    assert_eq!(
+        "< 1 2 3* 4 5 6 7 >",
+        page_items_to_string(
+            &Paginator::builder(7)
+                .current_page(3)
+                .layout(Layout::Logarithmic)
+                .build_paginator()
+                .unwrap()
+                .paginate()
+        )
+    );
+
+    for total_pages in 1..=120 {
+        for max_item_count in 7..=20 {
+            let iter = Paginator::builder(total_pages)
+                .max_item_count(max_item_count)
+                .layout(Layout::Logarithmic)
+                .build_paginator_iter()
+                .unwrap();
+
+            for paginator in iter {
+                let items = paginator.paginate();
+
+                assert!(items.len() <= max_item_count);
+                assert!(items.contains(&PageItem::CurrentPage(
+                    NonZeroUsize::new(paginator.current_page()).unwrap()
+                )));
+
+                let mut next_page = 1;
+
+                for item in items {
+                    match item {
+                        PageItem::Page(page) | PageItem::CurrentPage(page) => {
+                            assert!(next_page <= page.get());
+
+                            next_page = page.get() + 1;
+                        },
+                        PageItem::Ignore {
+                            start,
+                            end,
+                        } => {
+                            assert_eq!(next_page, start.get());
+                            assert!(start <= end);
+
+                            next_page = end.get() + 1;
+                        },
+                        _ => (),
+                    }
+                }
+
+                assert_eq!(total_pages + 1, next_page);
+            }
+        }
+    }
+}
+
+#[test]
+fn fixed_width() {
+    let builder = Paginator::builder(30).layout(Layout::Logarithmic).fixed_width(true);
+
+    assert_eq!(
+        "< 1 2* 3 4 ... 30 _ >",
+        page_items_to_string(
+            &builder.clone().current_page(2).build_paginator().unwrap().paginate()
+        )
+    );
+    assert_eq!(
+        "< 1 ... 25 ... 28 29 30* (",
+        page_items_to_string(&builder.current_page(30).build_paginator().unwrap().paginate())
+    );
+
+    for layout in [Layout::Window, Layout::Logarithmic] {
+        for total_pages in 1..=40 {
+            for max_item_count in 9..=13 {
+                let iter = Paginator::builder(total_pages)
+                    .max_item_count(max_item_count)
+                    .has_first(YesNoDepends::Depends)
+                    .has_last(YesNoDepends::Depends)
+                    .layout(layout)
+                    .fixed_width(true)
+                    .build_paginator_iter()
+                    .unwrap();
+
+                let item_counts: Vec<usize> =
+                    iter.map(|paginator| paginator.page_items().len()).collect();
+
+                assert!(item_counts.windows(2).all(|w| w[0] == w[1]), "{:?}", item_counts);
+                assert!(item_counts[0] <= max_item_count);
+            }
+        }
+    }
+}
+
+#[test]
+fn unknown_total_controls_with_few_known_pages() {
+    let paginate = |builder: PaginatorBuilder| {
+        page_items_to_string(&builder.build_paginator().unwrap().paginate())
+    };
+
+    assert_eq!("1* 2 ... >", paginate(PaginatorBuilder::unknown_total(1, true)));
+    assert_eq!("< 1 2* 3 ... >", paginate(PaginatorBuilder::unknown_total(2, true)));
+    assert_eq!(
+        "<< < 1 2* 3 ... >",
+        paginate(PaginatorBuilder::unknown_total(2, true).has_first(YesNoDepends::Depends))
+    );
+}
+
+#[test]
+fn unknown_total() {
+    let paginate = |builder: PaginatorBuilder| {
+        page_items_to_string(&builder.build_paginator().unwrap().paginate())
+    };
+
+    assert_eq!("< 1 ... 7 8 9* 10 ... >", paginate(PaginatorBuilder::unknown_total(9, true)));
+    assert_eq!("< 1 ... 4 5 6 7 8 9*", paginate(PaginatorBuilder::unknown_total(9, false)));
+    assert_eq!("1* 2 ... >", paginate(PaginatorBuilder::unknown_total(1, true)));
+    assert_eq!(
+        "< 1 2 3* 4 ...",
+        paginate(PaginatorBuilder::unknown_total(3, true).has_next(YesNoDepends::No))
+    );
+    assert_eq!(
+        "< 1 ... 98 99 100* 101 ... >",
+        paginate(PaginatorBuilder::unknown_total(100, true).layout(Layout::Logarithmic))
+    );
+    assert_eq!(
+        "<< < -10 1 ... 48 49 50* 51 ... +10 > ((",
+        paginate(
+            PaginatorBuilder::unknown_total(50, true)
+                .max_item_count(13)
+                .has_first(YesNoDepends::Depends)
+                .has_last(YesNoDepends::Yes)
+                .jump_steps(&[10])
+        )
+    );
+
+    // The known pages can be more than the current page.
+    assert_eq!(
+        "< 1 2* 3 4 5 ... >",
+        paginate(Paginator::builder(6).current_page(2).has_more(true))
+    );
+
+    let items = PaginatorBuilder::unknown_total(9, true).build_paginator().unwrap().paginate();
+
+    assert_eq!(
+        Some(&PageItem::More {
+            start: NonZeroUsize::new(11).unwrap()
+        }),
+        items.iter().rev().nth(1)
+    );
+}
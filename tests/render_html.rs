@@ -0,0 +1,254 @@
+use paginator::{
+    render::html::{HtmlClasses, HtmlLabels, HtmlRenderer},
+    PageItem, Paginator, YesNoDepends,
+};
+
+#[test]
+fn default_markup() {
+    let paginator = Paginator::builder(3).current_page(2).build_paginator().unwrap();
+
+    let mut html = String::new();
+
+    HtmlRenderer::new(|page| format!("/page/{}", page)).render(&paginator, &mut html).unwrap();
+
+    assert_eq!(
+        "<nav aria-label=\"Pagination\"><ul class=\"pagination\"><li><a href=\"/page/1\" \
+         aria-label=\"Previous page\">&laquo;</a></li><li><a href=\"/page/1\" aria-label=\"Page \
+         1\">1</a></li><li class=\"current\" aria-current=\"page\"><span aria-label=\"Page \
+         2\">2</span></li><li><a href=\"/page/3\" aria-label=\"Page 3\">3</a></li><li><a \
+         href=\"/page/3\" aria-label=\"Next page\">&raquo;</a></li></ul></nav>",
+        html
+    );
+}
+
+#[test]
+fn reserved_and_ignore_items() {
+    let paginator = Paginator::builder(20)
+        .has_prev(YesNoDepends::Yes)
+        .has_next(YesNoDepends::No)
+        .build_paginator()
+        .unwrap();
+
+    let mut html = String::new();
+
+    HtmlRenderer::new(|page| page).render(&paginator, &mut html).unwrap();
+
+    assert!(html.contains(
+        "<li class=\"disabled\"><span aria-disabled=\"true\" aria-label=\"Previous \
+         page\">&laquo;</span></li>"
+    ));
+    assert!(html.contains("<li class=\"ellipsis\"><span aria-hidden=\"true\">&hellip;</span></li>"));
+}
+
+#[test]
+fn escape_urls() {
+    let mut html = String::new();
+
+    HtmlRenderer::new(|page| format!("/search?q=\"a&b\"&page={}", page))
+        .render_items([PageItem::Page(core::num::NonZeroUsize::new(2).unwrap())], &mut html)
+        .unwrap();
+
+    assert!(html.contains("href=\"/search?q=&quot;a&amp;b&quot;&amp;page=2\""));
+}
+
+#[test]
+fn custom_classes_and_labels() {
+    let paginator = Paginator::builder(2).current_page(2).build_paginator().unwrap();
+
+    let mut html = String::new();
+
+    HtmlRenderer::new(|page| page)
+        .classes(HtmlClasses {
+            nav: "pager",
+            item: "item",
+            item_current: "active",
+            page: "link",
+            current: "link",
+            ..HtmlClasses::new()
+        })
+        .labels(HtmlLabels {
+            nav_aria: "Pages",
+            page_aria: "",
+            ..HtmlLabels::new()
+        })
+        .render(&paginator, &mut html)
+        .unwrap();
+
+    assert_eq!(
+        "<nav class=\"pager\" aria-label=\"Pages\"><ul class=\"pagination\"><li class=\"item\"><a \
+         class=\"link\" href=\"1\" aria-label=\"1\">1</a></li><li class=\"item active\" \
+         aria-current=\"page\"><span class=\"link\" aria-label=\"2\">2</span></li></ul></nav>",
+        html
+    );
+}
+
+#[test]
+fn bootstrap() {
+    let paginator = Paginator::builder(2).has_prev(YesNoDepends::Yes).build_paginator().unwrap();
+
+    let mut html = String::new();
+
+    HtmlRenderer::bootstrap(|page| page).render(&paginator, &mut html).unwrap();
+
+    assert_eq!(
+        "<nav aria-label=\"Pagination\"><ul class=\"pagination\"><li class=\"page-item \
+         disabled\"><span class=\"page-link\" aria-disabled=\"true\" aria-label=\"Previous \
+         page\">&laquo;</span></li><li class=\"page-item active\" aria-current=\"page\"><span \
+         class=\"page-link\" aria-label=\"Page 1\">1</span></li><li class=\"page-item\"><a \
+         class=\"page-link\" href=\"2\" aria-label=\"Page 2\">2</a></li></ul></nav>",
+        html
+    );
+}
+
+#[test]
+fn bulma() {
+    let paginator = Paginator::builder(3)
+        .has_prev(YesNoDepends::Yes)
+        .has_next(YesNoDepends::Yes)
+        .build_paginator()
+        .unwrap();
+
+    let mut html = String::new();
+
+    HtmlRenderer::bulma(|page| page).render(&paginator, &mut html).unwrap();
+
+    assert_eq!(
+        "<nav class=\"pagination\" aria-label=\"Pagination\"><span class=\"pagination-previous \
+         is-disabled\" aria-disabled=\"true\" aria-label=\"Previous page\">&laquo;</span><a \
+         class=\"pagination-next\" href=\"2\" aria-label=\"Next page\">&raquo;</a><ul \
+         class=\"pagination-list\"><li><a class=\"pagination-link is-current\" href=\"1\" \
+         aria-label=\"Page 1\" aria-current=\"page\">1</a></li><li><a class=\"pagination-link\" \
+         href=\"2\" aria-label=\"Page 2\">2</a></li><li><a class=\"pagination-link\" href=\"3\" \
+         aria-label=\"Page 3\">3</a></li></ul></nav>",
+        html
+    );
+}
+
+#[test]
+fn bulma_first_last() {
+    let paginator = Paginator::builder(3)
+        .current_page(2)
+        .has_first(YesNoDepends::Yes)
+        .has_last(YesNoDepends::Yes)
+        .build_paginator()
+        .unwrap();
+
+    let mut html = String::new();
+
+    HtmlRenderer::bulma(|page| page).render(&paginator, &mut html).unwrap();
+
+    assert_eq!(1, html.matches("<ul").count());
+    assert_eq!(
+        "<nav class=\"pagination\" aria-label=\"Pagination\"><a class=\"pagination-previous\" \
+         href=\"1\" aria-label=\"Previous page\">&laquo;</a><a class=\"pagination-next\" \
+         href=\"3\" aria-label=\"Next page\">&raquo;</a><ul class=\"pagination-list\"><li><a \
+         class=\"pagination-link\" href=\"1\" aria-label=\"First page\">&laquo; \
+         First</a></li><li><a class=\"pagination-link\" href=\"1\" aria-label=\"Page \
+         1\">1</a></li><li><a class=\"pagination-link is-current\" href=\"2\" aria-label=\"Page \
+         2\" aria-current=\"page\">2</a></li><li><a class=\"pagination-link\" href=\"3\" \
+         aria-label=\"Page 3\">3</a></li><li><a class=\"pagination-link\" href=\"3\" \
+         aria-label=\"Last page\">Last &raquo;</a></li></ul></nav>",
+        html
+    );
+}
+
+#[test]
+fn tailwind() {
+    let paginator = Paginator::builder(20).current_page(20).build_paginator().unwrap();
+
+    let mut html = String::new();
+
+    HtmlRenderer::tailwind(|page| page).render(&paginator, &mut html).unwrap();
+
+    assert!(html.starts_with(
+        "<nav aria-label=\"Pagination\"><ul class=\"inline-flex -space-x-px text-sm\"><li><a \
+         class=\"flex items-center justify-center px-3 h-8 leading-tight text-gray-500 bg-white \
+         border border-gray-300 rounded-s-lg hover:bg-gray-100 hover:text-gray-700\" href=\"19\""
+    ));
+    assert!(html.contains(
+        "<li><span class=\"flex items-center justify-center px-3 h-8 leading-tight text-gray-500 \
+         bg-white border border-gray-300\" aria-hidden=\"true\">&hellip;</span></li>"
+    ));
+    assert!(html.contains(
+        "<li aria-current=\"page\"><span class=\"flex items-center justify-center px-3 h-8 \
+         text-blue-600 border border-gray-300 bg-blue-50\" aria-label=\"Page 20\">20</span></li>"
+    ));
+}
+
+#[test]
+fn first_and_last() {
+    let paginator = Paginator::builder(3)
+        .current_page(3)
+        .has_first(YesNoDepends::Yes)
+        .has_last(YesNoDepends::Yes)
+        .build_paginator()
+        .unwrap();
+
+    let mut html = String::new();
+
+    HtmlRenderer::bootstrap(|page| page).render(&paginator, &mut html).unwrap();
+
+    assert!(html.contains(
+        "<li class=\"page-item\"><a class=\"page-link\" href=\"1\" aria-label=\"First \
+         page\">&laquo; First</a></li>"
+    ));
+    assert!(html.ends_with(
+        "<li class=\"page-item disabled\"><span class=\"page-link\" aria-disabled=\"true\" \
+         aria-label=\"Last page\">Last &raquo;</span></li></ul></nav>"
+    ));
+}
+
+#[test]
+fn jump_items() {
+    let paginator = Paginator::builder(50)
+        .current_page(25)
+        .max_item_count(11)
+        .jump_steps(&[10])
+        .build_paginator()
+        .unwrap();
+
+    let mut html = String::new();
+
+    HtmlRenderer::new(|page| page).render(&paginator, &mut html).unwrap();
+
+    assert!(html.contains("<li><a href=\"15\" aria-label=\"Jump back 10\">&minus;10</a></li>"));
+    assert!(html.contains("<li><a href=\"35\" aria-label=\"Jump forward 10\">+10</a></li>"));
+
+    // Bulma keeps the jumps in the page list.
+    html.clear();
+
+    HtmlRenderer::bulma(|page| page).render(&paginator, &mut html).unwrap();
+
+    assert_eq!(1, html.matches("<ul").count());
+    assert!(html.contains(
+        "<li><a class=\"pagination-link\" href=\"15\" aria-label=\"Jump back \
+         10\">&minus;10</a></li>"
+    ));
+
+    // On the first page, the backward jump has no target.
+    let paginator =
+        Paginator::builder(50).max_item_count(11).jump_steps(&[10]).build_paginator().unwrap();
+
+    html.clear();
+
+    HtmlRenderer::new(|page| page).render(&paginator, &mut html).unwrap();
+
+    assert!(html.contains(
+        "<li class=\"disabled\"><span aria-disabled=\"true\" aria-label=\"Jump back \
+         10\">&minus;10</span></li>"
+    ));
+    assert!(!html.contains("href=\"1\" aria-label=\"Jump back 10\""));
+}
+
+#[test]
+fn placeholder_items() {
+    let mut html = String::new();
+
+    HtmlRenderer::new(|page| page).render_items([PageItem::Placeholder], &mut html).unwrap();
+
+    assert_eq!(
+        "<nav aria-label=\"Pagination\"><ul class=\"pagination\"><li class=\"disabled\"><span \
+         aria-disabled=\"true\" aria-hidden=\"true\"></span></li></ul></nav>",
+        html
+    );
+}
@@ -0,0 +1,52 @@
+use paginator::PaginatorBuilder;
+
+#[test]
+fn default_format() {
+    let mut iter = PaginatorBuilder::from_items(95, 10).build_paginator_iter().unwrap();
+
+    assert_eq!("Showing 1–10 of 95 results", iter.next().unwrap().summary().unwrap().to_string());
+    assert_eq!("Showing 11–20 of 95 results", iter.next().unwrap().summary().unwrap().to_string());
+    assert_eq!("Showing 91–95 of 95 results", iter.last().unwrap().summary().unwrap().to_string());
+
+    let last = PaginatorBuilder::from_items(21, 10).current_page(3).build_paginator().unwrap();
+
+    assert_eq!("Showing 21 of 21 results", last.summary().unwrap().to_string());
+}
+
+#[test]
+fn empty_and_single() {
+    let empty = PaginatorBuilder::from_items(0, 10).build_paginator().unwrap().summary().unwrap();
+
+    assert_eq!(0, empty.first_item());
+    assert_eq!(0, empty.last_item());
+    assert_eq!("No results", empty.to_string());
+
+    let single = PaginatorBuilder::from_items(1, 10).build_paginator().unwrap().summary().unwrap();
+
+    assert_eq!("Showing 1 of 1 result", single.to_string());
+}
+
+#[test]
+fn unknown_items() {
+    assert!(PaginatorBuilder::new(5).build_paginator().unwrap().summary().is_none());
+    assert!(PaginatorBuilder::new(5).per_page(10).build_paginator().unwrap().summary().is_none());
+}
+
+#[test]
+fn custom_format() {
+    let summary = PaginatorBuilder::from_items(95, 10)
+        .current_page(3)
+        .build_paginator()
+        .unwrap()
+        .summary()
+        .unwrap();
+
+    assert_eq!(21, summary.first_item());
+    assert_eq!(30, summary.last_item());
+    assert_eq!(95, summary.total_items());
+    assert_eq!(
+        "Items 21 to 30 of 95 (page 3/10)",
+        summary.format("Items {first} to {last} of {total} (page {page}/{pages})").to_string()
+    );
+    assert_eq!("{unknown} {first", summary.format("{unknown} {first").to_string());
+}